@@ -0,0 +1,51 @@
+use quill::prelude::*;
+
+type Candle = (f64, f64, f64, f64, f64);
+
+fn price_data() -> (Vec<Candle>, Vec<f64>) {
+    // Deterministic pseudo-random walk of daily prices
+    let mut candles = Vec::new();
+    let mut volume = Vec::new();
+    let mut close: f64 = 100.0;
+    for day in 0..30 {
+        let t = day as f64;
+        let open = close;
+        let drift = (t * 0.7).sin() * 2.5 + (t * 1.3).cos() * 1.5;
+        close = open + drift;
+        let high = open.max(close) + 1.0 + (t * 2.1).sin().abs() * 1.5;
+        let low = open.min(close) - 1.0 - (t * 1.7).cos().abs() * 1.5;
+        candles.push((t, open, high, low, close));
+        volume.push(1000.0 + 600.0 * (t * 0.9).sin().abs() + drift.abs() * 200.0);
+    }
+    (candles, volume)
+}
+
+fn main() {
+    let (candles, volume) = price_data();
+    let moving_average: Vec<(f64, f64)> = candles
+        .windows(5)
+        .map(|w| (w[4].0, w.iter().map(|c| c.4).sum::<f64>() / 5.0))
+        .collect();
+
+    let plot = Plot::builder()
+        .dimensions((900, 500))
+        .title("Daily Prices")
+        .x_label("Trading Day")
+        .y_label("Price ($)")
+        .y_scale(Scale::None)
+        .legend(Legend::TopLeftInside)
+        .grid(Grid::Dotted)
+        .data([Series::builder()
+            .name("5-Day Average")
+            .color(Color::Navy)
+            .data(moving_average)
+            .line(Line::Dashed)
+            .build()])
+        .ohlc(vec![OhlcSeries::builder()
+            .name("ACME")
+            .data(candles)
+            .volume(volume)
+            .build()])
+        .build();
+    plot.to_svg("./gallery/candlestick.svg").unwrap();
+}
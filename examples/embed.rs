@@ -0,0 +1,63 @@
+use quill::prelude::*;
+
+fn main() {
+    let revenue: Vec<(f32, f32)> = [12.0, 15.5, 14.2, 18.9, 21.3, 24.0]
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| (i as f32 + 1.0, value))
+        .collect();
+    let costs: Vec<(f32, f32)> = [10.1, 11.0, 12.4, 12.9, 14.2, 15.1]
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| (i as f32 + 1.0, value))
+        .collect();
+
+    let trend = Plot::builder()
+        .dimensions((500, 320))
+        .title("Revenue and Costs (k$)")
+        .x_label("Month")
+        .legend(Legend::TopLeftInside)
+        .grid(Grid::Solid)
+        .data([
+            Series::builder().name("Revenue").data(revenue).marker(Marker::Circle).build(),
+            Series::builder().name("Costs").data(costs).line(Line::Dashed).build(),
+        ])
+        .build();
+
+    let share = PieChart::builder()
+        .dimensions((400, 320))
+        .title("Revenue by Region")
+        .inner_radius(0.5)
+        .slices(vec![
+            Slice::builder().name("North").value(42.0).build(),
+            Slice::builder().name("South").value(31.0).build(),
+            Slice::builder().name("West").value(27.0).build(),
+        ])
+        .build();
+
+    // Each chart gets its own id prefix so their clip paths do not collide on the page
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Dashboard</title>
+<style>
+  .quill-title {{ font-family: sans-serif; font-weight: bold; }}
+  .quill-series-0 {{ stroke-width: 2.5px; }}
+  .quill-grid {{ stroke-opacity: 0.5; }}
+</style>
+</head>
+<body>
+<svg viewBox="0 0 500 320" width="500">
+{}</svg>
+<svg viewBox="0 0 400 320" width="400">
+{}</svg>
+</body>
+</html>
+"#,
+        trend.to_svg_fragment("trend-").unwrap(),
+        share.to_svg_fragment("share-").unwrap(),
+    );
+    std::fs::write("./gallery/dashboard.html", html).unwrap();
+}
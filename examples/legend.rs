@@ -0,0 +1,65 @@
+use quill::prelude::*;
+
+fn harmonic(k: u32) -> Vec<(f64, f64)> {
+    // k-th odd harmonic of a square wave's Fourier series
+    let n = (2 * k - 1) as f64;
+    (0..=200)
+        .map(|i| {
+            let t = i as f64 * 0.05;
+            (t, 4.0 / (std::f64::consts::PI * n) * (n * t).sin())
+        })
+        .collect()
+}
+
+fn bound(y: f64) -> Series<'static, f64> {
+    // Reference lines share a single legend-only entry instead of listing each one
+    Series::builder()
+        .color(Color::Gray)
+        .line(Line::Dashed)
+        .data(vec![(0.0, y), (10.0, y)])
+        .show_in_legend(false)
+        .build()
+}
+
+fn main() {
+    let series = |k: u32, color: Color| {
+        Series::builder()
+            .name(["1st", "3rd", "5th", "7th", "9th", "11th"][k as usize - 1])
+            .color(color)
+            .data(harmonic(k))
+            .build()
+    };
+
+    let plot = Plot::builder()
+        .dimensions((800, 500))
+        .title("Square Wave Harmonics")
+        .x_label("t")
+        .y_label("Amplitude")
+        .y_scale(Scale::None)
+        .legend(Legend::BottomOutside)
+        .legend_config(LegendConfig {
+            title: Some("Harmonic".to_string()),
+            background: Color::Hex("#f2f2f2".to_string()),
+            corner_radius: 4.0,
+            show_border: false,
+            ..Default::default()
+        })
+        .layout(Layout::Tight)
+        .data([
+            series(1, Color::Blue),
+            series(2, Color::Orange),
+            series(3, Color::Green),
+            series(4, Color::Red),
+            series(5, Color::Purple),
+            series(6, Color::Brown),
+            bound(1.0),
+            bound(-1.0),
+        ])
+        .legend_entries(vec![LegendEntry::builder()
+            .name("±1")
+            .color(Color::Gray)
+            .line(Line::Dashed)
+            .build()])
+        .build();
+    plot.to_svg("./gallery/legend.svg").unwrap();
+}
@@ -20,7 +20,7 @@ fn main() {
         .legend(Legend::TopLeftInside)
         .grid(Grid::Solid)
         .font("Verdana")
-        .margin(Margin::default().add_left(10.0))
+        .layout(Layout::Tight)
         .axis(Axis::BottomLeft)
         .tick(Tick::Outward)
         .data([
@@ -0,0 +1,20 @@
+use quill::prelude::*;
+
+fn main() {
+    // Market share as a donut chart with the leading slice pulled out
+    let pie_chart = PieChart::builder()
+        .dimensions((700, 450))
+        .title("Browser Market Share")
+        .legend(Legend::RightCenterOutside)
+        .inner_radius(0.45)
+        .label_position(LabelPosition::Outside)
+        .slices(vec![
+            Slice::builder().name("Chrome").value(64.7).explode(0.08).build(),
+            Slice::builder().name("Safari").value(18.6).build(),
+            Slice::builder().name("Edge").value(5.3).build(),
+            Slice::builder().name("Firefox").value(2.8).build(),
+            Slice::builder().name("Other").value(8.6).color(Color::LightGray).build(),
+        ])
+        .build();
+    pie_chart.to_svg("./gallery/pie.svg").unwrap();
+}
@@ -0,0 +1,32 @@
+use quill::prelude::*;
+use std::f64::consts::PI;
+
+fn antenna_pattern() -> Vec<(f64, f64)> {
+    // Cardioid-like gain pattern in dB above a -30 dB floor
+    (0..=360)
+        .map(|deg| {
+            let theta = deg as f64 * PI / 180.0;
+            let gain = ((1.0 + theta.cos()) / 2.0).powi(2);
+            let db = 10.0 * gain.max(1e-3).log10();
+            (theta, db + 30.0)
+        })
+        .collect()
+}
+
+fn main() {
+    let polar_plot = PolarPlot::builder()
+        .dimensions((600, 520))
+        .title("Antenna Radiation Pattern")
+        .angle_zero(AngleZero::North)
+        .direction(Direction::Clockwise)
+        .legend(Legend::TopRightOutside)
+        .data([Series::builder()
+            .name("Gain (dB + 30)")
+            .color(Color::Blue)
+            .fill(Color::LightBlue)
+            .data(antenna_pattern())
+            .line_width(1.5)
+            .build()])
+        .build();
+    polar_plot.to_svg("./gallery/polar.svg").unwrap();
+}
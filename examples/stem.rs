@@ -0,0 +1,42 @@
+use quill::prelude::*;
+
+fn samples() -> Vec<(f64, f64)> {
+    // Damped sinusoid sampled at discrete time steps
+    (0..=24)
+        .map(|n| {
+            let n = n as f64;
+            (n, (-n / 10.0).exp() * (n * 0.6).cos())
+        })
+        .collect()
+}
+
+fn main() {
+    let held: Vec<(f64, f64)> = samples().into_iter().map(|(n, y)| (n, y * 0.5)).collect();
+
+    let stem_plot = Plot::builder()
+        .dimensions((800, 450))
+        .title("Discrete Signal")
+        .x_label("Sample n")
+        .y_label("x[n]")
+        .y_scale(Scale::None)
+        .legend(Legend::TopRightInside)
+        .data([
+            Series::builder()
+                .name("x[n]")
+                .color(Color::Blue)
+                .data(samples())
+                .stem(true)
+                .marker(Marker::Circle)
+                .marker_size(6.0)
+                .build(),
+            Series::builder()
+                .name("Zero-order hold")
+                .color(Color::Orange)
+                .fill(Color::Orange)
+                .data(held)
+                .interpolation(Interpolation::Step)
+                .build(),
+        ])
+        .build();
+    stem_plot.to_svg("./gallery/stem.svg").unwrap();
+}
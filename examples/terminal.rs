@@ -0,0 +1,38 @@
+use quill::prelude::*;
+
+fn main() {
+    let temperature: Vec<(f32, f32)> = (0..=48)
+        .map(|hour| {
+            let t = hour as f32 / 2.0;
+            (t, 14.0 + 6.0 * ((t - 9.0) * std::f32::consts::PI / 12.0).sin())
+        })
+        .collect();
+    let dew_point: Vec<(f32, f32)> = temperature.iter().map(|&(t, temp)| (t, temp * 0.6 + 2.0)).collect();
+
+    let plot = Plot::builder()
+        .title("Temperature Over a Day")
+        .x_label("Hour")
+        .y_label("°C")
+        .legend(Legend::TopRightInside)
+        .data([
+            Series::builder().name("Temperature").data(temperature).build(),
+            Series::builder()
+                .name("Dew point")
+                .data(dew_point)
+                .interpolation(Interpolation::Step)
+                .build(),
+        ])
+        .build();
+
+    // Braille characters with ANSI colors
+    print!("{}", plot.to_terminal(&TerminalConfig::default()).unwrap());
+
+    // Quadrant blocks without colors, for fonts or pipes that lack them
+    let config = TerminalConfig {
+        width: 60,
+        height: 18,
+        symbols: TerminalSymbols::Block,
+        color: false,
+    };
+    print!("{}", plot.to_terminal(&config).unwrap());
+}
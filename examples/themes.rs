@@ -0,0 +1,47 @@
+use quill::prelude::*;
+
+fn damped(frequency: f64, decay: f64) -> Vec<(f64, f64)> {
+    (0..=120)
+        .map(|i| {
+            let t = i as f64 * 0.1;
+            (t, (-decay * t).exp() * (frequency * t).cos())
+        })
+        .collect()
+}
+
+fn main() {
+    let themes = [
+        ("light", Theme::light()),
+        ("dark", Theme::dark()),
+        ("minimal", Theme::minimal()),
+        ("seaborn", Theme::seaborn()),
+        ("publication", Theme::publication()),
+    ];
+
+    for (name, theme) in &themes {
+        // Series leave their color unset and take it from the theme's color cycle
+        let series = |label: &'static str, frequency: f64, decay: f64| {
+            Series::builder()
+                .name(label)
+                .data(damped(frequency, decay))
+                .build()
+        };
+
+        let plot = Plot::builder()
+            .dimensions((640, 420))
+            .title("Damped Oscillations")
+            .x_label("Time (s)")
+            .y_label("Displacement")
+            .y_scale(Scale::None)
+            .legend(Legend::TopRightInside)
+            .data([
+                series("ζ = 0.05", 2.0, 0.1),
+                series("ζ = 0.15", 2.0, 0.3),
+                series("ζ = 0.30", 2.0, 0.6),
+                series("ζ = 0.50", 2.0, 1.0),
+            ])
+            .build()
+            .with_theme(theme);
+        plot.to_svg(&format!("./gallery/theme_{name}.svg")).unwrap();
+    }
+}
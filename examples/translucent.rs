@@ -0,0 +1,46 @@
+use quill::prelude::*;
+
+fn cluster(center: (f32, f32), spread: f32, count: usize) -> Vec<(f32, f32)> {
+    // Sunflower spiral: points spaced by the golden angle fill a disk evenly
+    let golden_angle = std::f32::consts::PI * (3.0 - 5.0f32.sqrt());
+    (0..count)
+        .map(|i| {
+            let r = spread * (i as f32 / count as f32).sqrt();
+            let theta = i as f32 * golden_angle;
+            (center.0 + r * theta.cos(), center.1 + r * theta.sin())
+        })
+        .collect()
+}
+
+fn main() {
+    let cloud = |name: &'static str, color: &str, center: (f32, f32)| {
+        Series::builder()
+            .name(name)
+            .color(color)
+            .data(cluster(center, 3.0, 300))
+            .marker(Marker::Circle)
+            .marker_size(9.0)
+            .line(Line::None)
+            .build()
+    };
+
+    let plot = Plot::builder()
+        .dimensions((600, 450))
+        .title("Overlapping Clusters")
+        .x_label("x")
+        .y_label("y")
+        .x_range(Range::Manual { min: -5.0, max: 5.0 })
+        .y_range(Range::Manual { min: -4.0, max: 4.0 })
+        .y_scale(Scale::None)
+        .legend(Legend::TopRightInside)
+        .grid_config(GridConfig {
+            color: Color::from("#0000001f"),
+            ..Default::default()
+        })
+        .data([
+            cloud("A", "rgba(31, 119, 180, 0.35)", (-1.5, 0.0)),
+            cloud("B", "hsla(28, 100%, 53%, 0.35)", (1.5, 0.5)),
+        ])
+        .build();
+    plot.to_svg("./gallery/translucent.svg").unwrap();
+}
@@ -0,0 +1,55 @@
+use quill::prelude::*;
+
+fn vortex() -> Vec<(f64, f64, f64, f64)> {
+    // Two counter-rotating vortices on a regular grid
+    let mut field = Vec::new();
+    for i in 0..=16 {
+        for j in 0..=12 {
+            let x = -4.0 + i as f64 * 0.5;
+            let y = -3.0 + j as f64 * 0.5;
+            let (mut u, mut v) = (0.0, 0.0);
+            for (cx, strength) in [(-1.5, 1.0), (1.5, -1.0)] {
+                let (dx, dy) = (x - cx, y);
+                let r2 = dx * dx + dy * dy + 0.5;
+                u += -strength * dy / r2;
+                v += strength * dx / r2;
+            }
+            field.push((x, y, u, v));
+        }
+    }
+    field
+}
+
+fn main() {
+    let quiver_plot = Plot::builder()
+        .dimensions((800, 560))
+        .title("Counter-Rotating Vortices")
+        .x_label("x")
+        .y_label("y")
+        .y_scale(Scale::None)
+        .grid(Grid::None)
+        .data([])
+        .vector_fields(vec![VectorField::builder()
+            .data(vortex())
+            .colormap(Colormap::Viridis)
+            .build()])
+        .build();
+    quiver_plot.to_svg("./gallery/quiver.svg").unwrap();
+
+    let streamline_plot = Plot::builder()
+        .dimensions((800, 560))
+        .title("Vortex Streamlines")
+        .x_label("x")
+        .y_label("y")
+        .y_scale(Scale::None)
+        .grid(Grid::None)
+        .data([])
+        .vector_fields(vec![VectorField::builder()
+            .data(vortex())
+            .mode(FieldMode::Streamlines)
+            .colormap(Colormap::Plasma)
+            .density(1.2)
+            .build()])
+        .build();
+    streamline_plot.to_svg("./gallery/streamlines.svg").unwrap();
+}
@@ -0,0 +1,278 @@
+//! Drawing primitives that charts are emitted to.
+//!
+//! Charts compute their layout once and describe it to a [`Backend`] as lines, paths,
+//! rectangles, circles and text, in pixels with the origin at the top left and y
+//! increasing downwards. [`SvgBackend`] builds the SVG document used by every export;
+//! implement the trait to draw charts elsewhere, such as on a GUI canvas.
+
+#[cfg(test)]
+pub(crate) mod recorder;
+mod svg;
+
+pub use svg::SvgBackend;
+
+use crate::color::Color;
+
+/// A point in pixels
+pub type Point = (f32, f32);
+
+/// Receives the primitives that make up a chart, in drawing order.
+///
+/// `begin` is called first with the size of the chart. Groups and clip regions nest, and
+/// every `begin_group` and `begin_clip` is matched by an `end_group` or `end_clip`.
+pub trait Backend {
+    /// Starts a chart of `width` by `height` pixels
+    fn begin(&mut self, width: f32, height: f32);
+
+    /// Draws a straight line
+    fn line(&mut self, from: Point, to: Point, stroke: &Stroke);
+
+    /// Draws a path, filling it before stroking it
+    fn path(&mut self, path: &Path, style: &ShapeStyle);
+
+    /// Draws a rectangle
+    fn rect(&mut self, rect: &Rect, style: &ShapeStyle);
+
+    /// Draws a circle
+    fn circle(&mut self, center: Point, radius: f32, style: &ShapeStyle);
+
+    /// Draws a run of text
+    fn text(&mut self, text: &Text, style: &TextStyle);
+
+    /// Starts a group of primitives that belong together, such as a legend glyph
+    fn begin_group(&mut self);
+
+    /// Ends the innermost group
+    fn end_group(&mut self);
+
+    /// Restricts drawing to `clip` until the matching `end_clip`.
+    ///
+    /// `id` names the region, such as `"plotAreaClip"`, and is unique within a chart.
+    fn begin_clip(&mut self, id: &str, clip: &Clip);
+
+    /// Ends the innermost clip region
+    fn end_clip(&mut self);
+
+    /// Marks the primitives that follow, up to the next call, as drawing `role`.
+    ///
+    /// Backends that do not label chart parts can ignore it, as the default does.
+    fn set_role(&mut self, _role: Role) {}
+}
+
+/// Part of a chart that primitives belong to, such as the grid or the second series
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    Background,
+    /// Background of the plot area inside the axes
+    PlotArea,
+    Title,
+    AxisLabel,
+    Axis,
+    Grid,
+    Tick,
+    TickLabel,
+    /// A data series by its index
+    Series(usize),
+    /// An OHLC series by its index
+    Ohlc(usize),
+    /// A vector field by its index
+    VectorField(usize),
+    /// A pie slice by its index
+    Slice(usize),
+    /// Percentage or value labels of pie slices, with their leader lines
+    SliceLabel,
+    Legend,
+}
+
+/// Outline of a line or shape
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stroke {
+    pub color: Color,
+    pub width: f32,
+    /// Alternating dash and gap lengths, empty for a solid line
+    pub dash: &'static [f32],
+}
+
+impl Stroke {
+    /// A solid stroke
+    pub fn new(color: Color, width: f32) -> Self {
+        Self {
+            color,
+            width,
+            dash: &[],
+        }
+    }
+}
+
+/// Fill and outline of a shape; either may be left out
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShapeStyle {
+    pub fill: Option<Color>,
+    pub stroke: Option<Stroke>,
+}
+
+impl ShapeStyle {
+    /// Filled without an outline
+    pub fn filled(color: Color) -> Self {
+        Self {
+            fill: Some(color),
+            stroke: None,
+        }
+    }
+
+    /// Outlined without a fill
+    pub fn stroked(stroke: Stroke) -> Self {
+        Self {
+            fill: None,
+            stroke: Some(stroke),
+        }
+    }
+}
+
+/// An axis-aligned rectangle, optionally with rounded corners
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub corner_radius: f32,
+}
+
+impl Rect {
+    /// A rectangle with square corners
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            corner_radius: 0.0,
+        }
+    }
+}
+
+/// A step along a [`Path`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Segment {
+    /// Starts a new subpath
+    MoveTo(Point),
+    LineTo(Point),
+    /// Cubic Bézier curve through two control points
+    CubicTo(Point, Point, Point),
+    /// Circular arc of `radius`, taking the longer way round when `large_arc` is set and
+    /// running clockwise on screen when `sweep` is set
+    ArcTo {
+        radius: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: Point,
+    },
+    /// Closes the current subpath
+    Close,
+}
+
+/// Outline made of straight, curved and arc segments, built up like SVG path data.
+///
+/// Overlapping subpaths follow the nonzero fill rule.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path {
+    pub segments: Vec<Segment>,
+}
+
+impl Path {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn move_to(mut self, point: Point) -> Self {
+        self.segments.push(Segment::MoveTo(point));
+        self
+    }
+
+    pub fn line_to(mut self, point: Point) -> Self {
+        self.segments.push(Segment::LineTo(point));
+        self
+    }
+
+    pub fn cubic_to(mut self, control1: Point, control2: Point, to: Point) -> Self {
+        self.segments.push(Segment::CubicTo(control1, control2, to));
+        self
+    }
+
+    pub fn arc_to(mut self, radius: f32, large_arc: bool, sweep: bool, to: Point) -> Self {
+        self.segments.push(Segment::ArcTo {
+            radius,
+            large_arc,
+            sweep,
+            to,
+        });
+        self
+    }
+
+    pub fn close(mut self) -> Self {
+        self.segments.push(Segment::Close);
+        self
+    }
+}
+
+/// Horizontal alignment of text relative to its position
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextAnchor {
+    Start,
+    Middle,
+    End,
+}
+
+/// Vertical alignment of text relative to its position
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextBaseline {
+    /// The top of the text is at the position
+    Top,
+    /// The hanging baseline, just below the top of capital letters, is at the position
+    Hanging,
+    Middle,
+    /// The bottom of the text is at the position
+    Bottom,
+}
+
+/// Font, color and alignment of text
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextStyle<'a> {
+    pub font: &'a str,
+    pub size: f32,
+    pub color: Color,
+    pub bold: bool,
+    pub anchor: TextAnchor,
+    pub baseline: TextBaseline,
+}
+
+/// A run of text at a position
+#[derive(Clone, Debug, PartialEq)]
+pub struct Text<'a> {
+    pub position: Point,
+    pub content: &'a str,
+    /// Exponent raised after the content by 0.4em and pulled 0.2em towards it, as in 10³
+    pub superscript: Option<&'a str>,
+    /// Clockwise rotation in degrees about the position
+    pub rotation: f32,
+}
+
+impl<'a> Text<'a> {
+    /// Unrotated text without a superscript
+    pub fn new(position: Point, content: &'a str) -> Self {
+        Self {
+            position,
+            content,
+            superscript: None,
+            rotation: 0.0,
+        }
+    }
+}
+
+/// Shape of a clip region
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Clip {
+    Rect(Rect),
+    Circle { center: Point, radius: f32 },
+}
@@ -0,0 +1,91 @@
+// A backend that keeps the paths and text it receives, for tests of chart geometry
+
+use crate::backend::{
+    Backend, Clip, Path, Point, Rect, Role, Segment, ShapeStyle, Stroke, Text, TextStyle,
+};
+
+/// Records paths and text with the role set when each was drawn
+pub(crate) struct Recorder {
+    role: Role,
+    paths: Vec<(Role, Path)>,
+    texts: Vec<(Role, String, Point)>,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self {
+            role: Role::Background,
+            paths: Vec::new(),
+            texts: Vec::new(),
+        }
+    }
+}
+
+impl Recorder {
+    /// Paths drawn with `role`, in drawing order
+    pub fn paths(&self, role: Role) -> Vec<&Path> {
+        self.paths.iter().filter(|(r, _)| *r == role).map(|(_, path)| path).collect()
+    }
+
+    /// Content and position of the text drawn with `role`, in drawing order
+    pub fn texts(&self, role: Role) -> Vec<(&str, Point)> {
+        self.texts
+            .iter()
+            .filter(|(r, _, _)| *r == role)
+            .map(|(_, content, position)| (content.as_str(), *position))
+            .collect()
+    }
+}
+
+impl Backend for Recorder {
+    fn begin(&mut self, _width: f32, _height: f32) {}
+
+    fn line(&mut self, _from: Point, _to: Point, _stroke: &Stroke) {}
+
+    fn path(&mut self, path: &Path, _style: &ShapeStyle) {
+        self.paths.push((self.role, path.clone()));
+    }
+
+    fn rect(&mut self, _rect: &Rect, _style: &ShapeStyle) {}
+
+    fn circle(&mut self, _center: Point, _radius: f32, _style: &ShapeStyle) {}
+
+    fn text(&mut self, text: &Text, _style: &TextStyle) {
+        self.texts.push((self.role, text.content.to_string(), text.position));
+    }
+
+    fn begin_group(&mut self) {}
+
+    fn end_group(&mut self) {}
+
+    fn begin_clip(&mut self, _id: &str, _clip: &Clip) {}
+
+    fn end_clip(&mut self) {}
+
+    fn set_role(&mut self, role: Role) {
+        self.role = role;
+    }
+}
+
+/// Segments of `path` with coordinates and radii rounded to hundredths of a pixel, so that
+/// they compare equal to exact expected values
+pub(crate) fn rounded(path: &Path) -> Vec<Segment> {
+    // Adding zero turns -0.0 into 0.0
+    let round = |v: f32| (v * 100.0).round() / 100.0 + 0.0;
+    let point = |(x, y): Point| (round(x), round(y));
+    path.segments
+        .iter()
+        .map(|segment| match *segment {
+            Segment::MoveTo(to) => Segment::MoveTo(point(to)),
+            Segment::LineTo(to) => Segment::LineTo(point(to)),
+            Segment::CubicTo(a, b, to) => Segment::CubicTo(point(a), point(b), point(to)),
+            Segment::ArcTo { radius, large_arc, sweep, to } => Segment::ArcTo {
+                radius: round(radius),
+                large_arc,
+                sweep,
+                to: point(to),
+            },
+            Segment::Close => Segment::Close,
+        })
+        .collect()
+}
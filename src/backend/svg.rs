@@ -0,0 +1,391 @@
+use super::{
+    Backend, Clip, Path, Point, Rect, Role, Segment, ShapeStyle, Stroke, Text, TextAnchor,
+    TextBaseline, TextStyle,
+};
+use crate::draw::Paint;
+use svg::node::element::path::Data;
+use svg::node::element::{
+    Circle, ClipPath, Definitions, Group, Line as SvgLine, Path as SvgPath, Rectangle, TSpan,
+    Text as SvgText,
+};
+use svg::node::{Text as SvgNodeText, Value};
+use svg::{Document, Node};
+
+/// Builds an SVG document from the primitives of a chart; the backend behind every export.
+///
+/// Created with [`SvgBackend::fragment`], it builds a `<g>` element for embedding in a larger
+/// document instead, with its ids prefixed and its elements marked with `quill-` classes.
+#[derive(Debug)]
+pub struct SvgBackend {
+    document: Document,
+    /// Open groups and clip regions, innermost last
+    groups: Vec<Group>,
+    /// Builds a `<g>` fragment with CSS classes instead of a document
+    fragment: bool,
+    /// Prepended to every generated id
+    id_prefix: String,
+    /// Classes of the current role, only tracked for fragments
+    class: Option<String>,
+}
+
+impl Default for SvgBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SvgBackend {
+    pub fn new() -> Self {
+        Self {
+            document: Document::new(),
+            groups: Vec::new(),
+            fragment: false,
+            id_prefix: String::new(),
+            class: None,
+        }
+    }
+
+    /// A backend building a `<g class="quill-chart">` element without a size, whose ids
+    /// start with `id_prefix` so that several charts can share one page
+    pub fn fragment(id_prefix: &str) -> Self {
+        Self {
+            fragment: true,
+            id_prefix: id_prefix.to_string(),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the finished document, closing any groups left open
+    pub fn into_document(mut self) -> Document {
+        while let Some(group) = self.groups.pop() {
+            self.add(group);
+        }
+        self.document
+    }
+
+    /// Returns the finished fragment, closing any groups left open
+    pub fn into_fragment(mut self) -> Group {
+        let mut fragment = Group::new();
+        while let Some(group) = self.groups.pop() {
+            match self.groups.last_mut() {
+                Some(parent) => parent.append(group),
+                None => fragment = group,
+            }
+        }
+        fragment
+    }
+
+    fn add(&mut self, node: impl Into<Box<dyn Node>>) {
+        match self.groups.last_mut() {
+            Some(group) => group.append(node),
+            None => self.document.append(node),
+        }
+    }
+
+    /// Adds a drawn element, marked with the classes of the current role
+    fn add_shape<N: Node>(&mut self, node: N) {
+        match self.class.clone() {
+            Some(class) => self.add(set(node, "class", class)),
+            None => self.add(node),
+        }
+    }
+}
+
+/// Space-separated `quill-` classes for a role, with an indexed class for each series,
+/// field or slice
+fn role_classes(role: Role) -> String {
+    let (name, index) = match role {
+        Role::Background => ("background", None),
+        Role::PlotArea => ("plot-area", None),
+        Role::Title => ("title", None),
+        Role::AxisLabel => ("axis-label", None),
+        Role::Axis => ("axis", None),
+        Role::Grid => ("grid", None),
+        Role::Tick => ("tick", None),
+        Role::TickLabel => ("tick-label", None),
+        Role::Series(index) => ("series", Some(index)),
+        Role::Ohlc(index) => ("ohlc", Some(index)),
+        Role::VectorField(index) => ("vector-field", Some(index)),
+        Role::Slice(index) => ("slice", Some(index)),
+        Role::SliceLabel => ("slice-label", None),
+        Role::Legend => ("legend", None),
+    };
+    match index {
+        Some(index) => format!("quill-{name} quill-{name}-{index}"),
+        None => format!("quill-{name}"),
+    }
+}
+
+/// Escapes the characters that would otherwise be read as XML markup in text content
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Sets `fill` and the stroke attributes, with `fill="none"` for unfilled shapes
+fn apply_style<N: Node>(node: N, style: &ShapeStyle) -> N {
+    let node = match &style.fill {
+        Some(color) => node.fill_color(color),
+        None => set(node, "fill", "none"),
+    };
+    match &style.stroke {
+        Some(stroke) => apply_stroke(node, stroke),
+        None => node,
+    }
+}
+
+fn apply_stroke<N: Node>(node: N, stroke: &Stroke) -> N {
+    let node = set(node.stroke_color(&stroke.color), "stroke-width", stroke.width);
+    if stroke.dash.is_empty() {
+        return node;
+    }
+    let dash: Vec<String> = stroke.dash.iter().map(|length| length.to_string()).collect();
+    set(node, "stroke-dasharray", dash.join(" "))
+}
+
+/// Sets an attribute on any element, as `set` is only defined on each element type
+fn set<N: Node>(mut node: N, name: &str, value: impl Into<Value>) -> N {
+    node.assign(name, value);
+    node
+}
+
+fn path_data(path: &Path) -> Data {
+    path.segments.iter().fold(Data::new(), |data, segment| match *segment {
+        Segment::MoveTo(point) => data.move_to(point),
+        Segment::LineTo(point) => data.line_to(point),
+        Segment::CubicTo(control1, control2, to) => data.cubic_curve_to((control1, control2, to)),
+        Segment::ArcTo {
+            radius,
+            large_arc,
+            sweep,
+            to: (x, y),
+        } => {
+            let (large_arc, sweep) = (large_arc as u8 as f32, sweep as u8 as f32);
+            data.elliptical_arc_to(vec![radius, radius, 0.0, large_arc, sweep, x, y])
+        }
+        Segment::Close => data.close(),
+    })
+}
+
+fn rectangle(rect: &Rect) -> Rectangle {
+    let rectangle = Rectangle::new()
+        .set("x", rect.x)
+        .set("y", rect.y)
+        .set("width", rect.width)
+        .set("height", rect.height);
+    if rect.corner_radius > 0.0 {
+        rectangle.set("rx", rect.corner_radius)
+    } else {
+        rectangle
+    }
+}
+
+impl Backend for SvgBackend {
+    fn begin(&mut self, width: f32, height: f32) {
+        self.groups.clear();
+        self.class = None;
+        if self.fragment {
+            self.groups.push(Group::new().set("class", "quill-chart"));
+        } else {
+            self.document = Document::new()
+                .set("width", width)
+                .set("height", height)
+                .set("viewBox", (0, 0, width, height));
+        }
+    }
+
+    fn line(&mut self, from: Point, to: Point, stroke: &Stroke) {
+        let line = SvgLine::new()
+            .set("x1", from.0)
+            .set("y1", from.1)
+            .set("x2", to.0)
+            .set("y2", to.1);
+        self.add_shape(apply_stroke(line, stroke));
+    }
+
+    fn path(&mut self, path: &Path, style: &ShapeStyle) {
+        let path = SvgPath::new().set("d", path_data(path));
+        self.add_shape(apply_style(path, style));
+    }
+
+    fn rect(&mut self, rect: &Rect, style: &ShapeStyle) {
+        self.add_shape(apply_style(rectangle(rect), style));
+    }
+
+    fn circle(&mut self, center: Point, radius: f32, style: &ShapeStyle) {
+        let circle = Circle::new()
+            .set("cx", center.0)
+            .set("cy", center.1)
+            .set("r", radius);
+        self.add_shape(apply_style(circle, style));
+    }
+
+    fn text(&mut self, text: &Text, style: &TextStyle) {
+        let (x, y) = text.position;
+        let mut node = SvgText::new()
+            .set("x", x)
+            .set("y", y)
+            .set("font-family", style.font)
+            .set("font-size", style.size);
+        if style.bold {
+            node = node.set("font-weight", "bold");
+        }
+        let anchor = match style.anchor {
+            TextAnchor::Start => "start",
+            TextAnchor::Middle => "middle",
+            TextAnchor::End => "end",
+        };
+        let baseline = match style.baseline {
+            TextBaseline::Top => "text-before-edge",
+            TextBaseline::Hanging => "hanging",
+            TextBaseline::Middle => "middle",
+            TextBaseline::Bottom => "text-after-edge",
+        };
+        node = node
+            .fill_color(&style.color)
+            .set("text-anchor", anchor)
+            .set("dominant-baseline", baseline);
+        if text.rotation != 0.0 {
+            node = node.set("transform", format!("rotate({}, {}, {})", text.rotation, x, y));
+        }
+        node = node.add(SvgNodeText::new(escape(text.content)));
+        if let Some(superscript) = text.superscript {
+            let exponent = TSpan::new()
+                .set("dy", "-0.4em")
+                .set("dx", "-0.2em")
+                .add(SvgNodeText::new(escape(superscript)));
+            node = node.add(exponent);
+        }
+        self.add_shape(node);
+    }
+
+    fn begin_group(&mut self) {
+        self.groups.push(Group::new());
+    }
+
+    fn end_group(&mut self) {
+        if let Some(group) = self.groups.pop() {
+            self.add(group);
+        }
+    }
+
+    fn begin_clip(&mut self, id: &str, clip: &Clip) {
+        let shape: Box<dyn Node> = match *clip {
+            Clip::Rect(rect) => Box::new(rectangle(&rect)),
+            Clip::Circle { center, radius } => Box::new(
+                Circle::new()
+                    .set("cx", center.0)
+                    .set("cy", center.1)
+                    .set("r", radius),
+            ),
+        };
+        let id = format!("{}{id}", self.id_prefix);
+        let clip_path = ClipPath::new().set("id", id.as_str()).add(shape);
+        self.add(Definitions::new().add(clip_path));
+        self.groups
+            .push(Group::new().set("clip-path", format!("url(#{id})")));
+    }
+
+    fn end_clip(&mut self) {
+        self.end_group();
+    }
+
+    fn set_role(&mut self, role: Role) {
+        if self.fragment {
+            self.class = Some(role_classes(role));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Color;
+
+    /// A minimal chart: a background, then a series line inside a clip region
+    fn draw(backend: &mut SvgBackend) {
+        backend.begin(100.0, 50.0);
+        backend.set_role(Role::Background);
+        backend.rect(&Rect::new(0.0, 0.0, 100.0, 50.0), &ShapeStyle::filled(Color::White));
+        backend.begin_clip("plotAreaClip", &Clip::Rect(Rect::new(10.0, 10.0, 80.0, 30.0)));
+        backend.set_role(Role::Series(2));
+        backend.line((10.0, 40.0), (90.0, 10.0), &Stroke::new(Color::Blue, 1.0));
+        backend.end_clip();
+    }
+
+    #[test]
+    fn fragment_prefixes_ids_and_adds_classes() {
+        let mut backend = SvgBackend::fragment("sales-");
+        draw(&mut backend);
+        let svg = backend.into_fragment().to_string();
+
+        assert!(svg.starts_with("<g class=\"quill-chart\">"), "{svg}");
+        assert!(svg.contains("id=\"sales-plotAreaClip\""), "{svg}");
+        assert!(svg.contains("clip-path=\"url(#sales-plotAreaClip)\""), "{svg}");
+        assert!(!svg.contains("\"plotAreaClip\"") && !svg.contains("#plotAreaClip"), "{svg}");
+        assert!(svg.contains("class=\"quill-background\""), "{svg}");
+        assert!(svg.contains("class=\"quill-series quill-series-2\""), "{svg}");
+    }
+
+    #[test]
+    fn document_has_size_and_no_classes() {
+        let mut backend = SvgBackend::new();
+        draw(&mut backend);
+        let svg = backend.into_document().to_string();
+
+        assert!(svg.contains("width=\"100\"") && svg.contains("height=\"50\""), "{svg}");
+        assert!(svg.contains("id=\"plotAreaClip\""), "{svg}");
+        assert!(!svg.contains("class="), "{svg}");
+    }
+
+    #[test]
+    fn text_is_escaped() {
+        let mut backend = SvgBackend::new();
+        backend.begin(100.0, 50.0);
+        let style = TextStyle {
+            font: "serif",
+            size: 12.0,
+            color: Color::Black,
+            bold: false,
+            anchor: TextAnchor::Start,
+            baseline: TextBaseline::Middle,
+        };
+        let text = Text {
+            superscript: Some("<2>"),
+            ..Text::new((10.0, 10.0), "a < b & c")
+        };
+        backend.text(&text, &style);
+        let svg = backend.into_document().to_string();
+        assert!(svg.contains("\na &lt; b &amp; c\n"), "{svg}");
+        assert!(svg.contains("\n&lt;2&gt;\n"), "{svg}");
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn markup_characters_in_a_title_render_to_png() {
+        use crate::prelude::*;
+
+        let plot = Plot::builder()
+            .title("a < b & c")
+            .legend(Legend::TopRightInside)
+            .data([Series::builder().name("<x> & y").data(vec![(0.0, 1.0), (1.0, 2.0)]).build()])
+            .build();
+        assert!(plot.to_png_bytes(1.0).is_ok());
+    }
+
+    #[test]
+    fn role_classes_index_series_fields_and_slices() {
+        assert_eq!(role_classes(Role::TickLabel), "quill-tick-label");
+        assert_eq!(role_classes(Role::Ohlc(0)), "quill-ohlc quill-ohlc-0");
+        assert_eq!(role_classes(Role::VectorField(1)), "quill-vector-field quill-vector-field-1");
+        assert_eq!(role_classes(Role::Slice(3)), "quill-slice quill-slice-3");
+    }
+}
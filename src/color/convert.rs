@@ -0,0 +1,198 @@
+use super::Color;
+
+/// An RGB color carrying over `alpha`, kept as a plain `Rgb` when opaque
+fn with_alpha(rgb: (u8, u8, u8), alpha: f32) -> Color {
+    if alpha < 1.0 {
+        Color::Rgba(rgb.0, rgb.1, rgb.2, alpha)
+    } else {
+        Color::Rgb(rgb.0, rgb.1, rgb.2)
+    }
+}
+
+fn to_channel(value: f32) -> u8 {
+    (value * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+/// sRGB channel to linear light
+fn linearize(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+/// Linear light to sRGB channel
+fn delinearize(value: f32) -> u8 {
+    let c = if value <= 0.0031308 { value * 12.92 } else { 1.055 * value.powf(1.0 / 2.4) - 0.055 };
+    to_channel(c)
+}
+
+/// Hue in degrees from the largest channel and the chroma, shared by HSL and HSV
+fn hue(r: f32, g: f32, b: f32, max: f32, chroma: f32) -> f32 {
+    if chroma == 0.0 {
+        return 0.0;
+    }
+    let sector = if max == r {
+        ((g - b) / chroma).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+    sector * 60.0
+}
+
+/// RGB from a hue in degrees and the chroma, offset by `m` in every channel
+fn from_hue(hue: f32, chroma: f32, m: f32) -> (u8, u8, u8) {
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    (to_channel(r + m), to_channel(g + m), to_channel(b + m))
+}
+
+impl Color {
+    /// Hue in degrees (0.0..360.0) with saturation and lightness from 0.0 to 1.0
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_rgb();
+        let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let chroma = max - min;
+        let lightness = (max + min) / 2.0;
+        let saturation = if chroma == 0.0 {
+            0.0
+        } else {
+            chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        (hue(r, g, b, max, chroma), saturation, lightness)
+    }
+
+    /// Create a color from a hue in degrees and saturation and lightness from 0.0 to 1.0
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let (saturation, lightness) = (saturation.clamp(0.0, 1.0), lightness.clamp(0.0, 1.0));
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        from_hue(hue, chroma, lightness - chroma / 2.0).into()
+    }
+
+    /// Hue in degrees (0.0..360.0) with saturation and value from 0.0 to 1.0
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_rgb();
+        let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let chroma = max - r.min(g).min(b);
+        let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+        (hue(r, g, b, max, chroma), saturation, max)
+    }
+
+    /// Create a color from a hue in degrees and saturation and value from 0.0 to 1.0
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let (saturation, value) = (saturation.clamp(0.0, 1.0), value.clamp(0.0, 1.0));
+        let chroma = value * saturation;
+        from_hue(hue, chroma, value - chroma).into()
+    }
+
+    /// Adjusts HSL saturation and lightness by the given amounts, keeping hue and alpha
+    fn adjust_hsl(&self, saturation: f32, lightness: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        with_alpha(Color::from_hsl(h, s + saturation, l + lightness).to_rgb(), self.alpha())
+    }
+
+    /// Raises the HSL lightness by `amount` (0.0 to 1.0), e.g. 0.2 for 20% lighter
+    pub fn lighten(&self, amount: f32) -> Self {
+        self.adjust_hsl(0.0, amount)
+    }
+
+    /// Lowers the HSL lightness by `amount` (0.0 to 1.0)
+    pub fn darken(&self, amount: f32) -> Self {
+        self.adjust_hsl(0.0, -amount)
+    }
+
+    /// Raises the HSL saturation by `amount` (0.0 to 1.0)
+    pub fn saturate(&self, amount: f32) -> Self {
+        self.adjust_hsl(amount, 0.0)
+    }
+
+    /// Lowers the HSL saturation by `amount` (0.0 to 1.0); 1.0 gives a gray
+    pub fn desaturate(&self, amount: f32) -> Self {
+        self.adjust_hsl(-amount, 0.0)
+    }
+
+    /// Blends channel by channel in sRGB, from `self` at `t = 0.0` to `other` at `t = 1.0`
+    pub fn mix(&self, other: &Color, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let (r0, g0, b0, a0) = self.to_rgba();
+        let (r1, g1, b1, a1) = other.to_rgba();
+        let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        with_alpha((lerp(r0, r1), lerp(g0, g1), lerp(b0, b1)), a0 + (a1 - a0) * t)
+    }
+
+    /// Blends in the OKLab color space, which keeps perceived lightness and hue changing
+    /// evenly and avoids the muddy midpoints of sRGB mixing
+    pub fn mix_oklab(&self, other: &Color, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let (l0, a0, b0) = self.to_oklab();
+        let (l1, a1, b1) = other.to_oklab();
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        let alpha = lerp(self.alpha(), other.alpha());
+        let mixed = Color::from_oklab(lerp(l0, l1), lerp(a0, a1), lerp(b0, b1));
+        with_alpha(mixed.to_rgb(), alpha)
+    }
+
+    /// Composites this color over an opaque `background` using its alpha
+    pub fn over(&self, background: &Color) -> Self {
+        let (r, g, b, a) = self.to_rgba();
+        Color::from(background.to_rgb()).mix(&Color::Rgb(r, g, b), a)
+    }
+
+    /// Lightness, green–red and blue–yellow coordinates in OKLab
+    pub fn to_oklab(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_rgb();
+        let (r, g, b) = (linearize(r), linearize(g), linearize(b));
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        (
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        )
+    }
+
+    /// Create a color from OKLab coordinates, clipping to the sRGB gamut
+    pub fn from_oklab(lightness: f32, a: f32, b: f32) -> Self {
+        let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+        let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+        let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+        Color::Rgb(
+            delinearize(4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s),
+            delinearize(-1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s),
+            delinearize(-0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s),
+        )
+    }
+
+    /// WCAG relative luminance, from 0.0 for black to 1.0 for white
+    pub fn relative_luminance(&self) -> f32 {
+        let (r, g, b) = self.to_rgb();
+        0.2126 * linearize(r) + 0.7152 * linearize(g) + 0.0722 * linearize(b)
+    }
+
+    /// WCAG contrast ratio between two colors, from 1.0 to 21.0
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Black or white, whichever is more legible on top of this color
+    pub fn contrast_text(&self) -> Self {
+        if self.contrast_ratio(&Color::Black) >= self.contrast_ratio(&Color::White) {
+            Color::Black
+        } else {
+            Color::White
+        }
+    }
+}
@@ -0,0 +1,166 @@
+mod convert;
+mod named;
+mod parse;
+
+pub use parse::ColorParseError;
+pub(crate) use parse::validate_colors;
+
+use parse::parse_hex;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Color {
+    // Popular colors with their RGB values
+    #[default]
+    Black,
+    White,
+    Red,
+    Green,
+    Blue,
+    Yellow,
+    Orange,
+    Purple,
+    Pink,
+    Brown,
+    Gray,
+    DarkGray,
+    LightGray,
+    Cyan,
+    Magenta,
+    Lime,
+    Navy,
+    Teal,
+    Silver,
+    Maroon,
+    Olive,
+    Aqua,
+    Fuchsia,
+    DarkRed,
+    DarkGreen,
+    DarkBlue,
+    LightRed,
+    LightGreen,
+    LightBlue,
+    Crimson,
+    Gold,
+    Indigo,
+    Coral,
+    Salmon,
+    Khaki,
+    Violet,
+    // Custom color variants
+    Rgb(u8, u8, u8),
+    /// Red, green and blue with an alpha from 0.0 (transparent) to 1.0 (opaque)
+    Rgba(u8, u8, u8, f32),
+    /// `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`, with or without the `#`
+    Hex(String),
+    /// A string that is not a color, kept by `From<&str>` so that rendering reports it
+    /// instead of silently drawing black
+    Invalid(String),
+}
+
+impl Color {
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        let (r, g, b, _) = self.to_rgba();
+        (r, g, b)
+    }
+
+    /// Red, green and blue components with the alpha from 0.0 to 1.0
+    pub fn to_rgba(&self) -> (u8, u8, u8, f32) {
+        let rgb = match self {
+            Color::Black => (0, 0, 0),
+            Color::White => (255, 255, 255),
+            Color::Red => (255, 0, 0),
+            Color::Green => (0, 128, 0),
+            Color::Blue => (0, 0, 255),
+            Color::Yellow => (255, 255, 0),
+            Color::Orange => (255, 165, 0),
+            Color::Purple => (128, 0, 128),
+            Color::Pink => (255, 192, 203),
+            Color::Brown => (165, 42, 42),
+            Color::Gray => (128, 128, 128),
+            Color::DarkGray => (64, 64, 64),
+            Color::LightGray => (192, 192, 192),
+            Color::Cyan => (0, 255, 255),
+            Color::Magenta => (255, 0, 255),
+            Color::Lime => (0, 255, 0),
+            Color::Navy => (0, 0, 128),
+            Color::Teal => (0, 128, 128),
+            Color::Silver => (192, 192, 192),
+            Color::Maroon => (128, 0, 0),
+            Color::Olive => (128, 128, 0),
+            Color::Aqua => (0, 255, 255),
+            Color::Fuchsia => (255, 0, 255),
+            Color::DarkRed => (139, 0, 0),
+            Color::DarkGreen => (0, 100, 0),
+            Color::DarkBlue => (0, 0, 139),
+            Color::LightRed => (255, 182, 193),
+            Color::LightGreen => (144, 238, 144),
+            Color::LightBlue => (173, 216, 230),
+            Color::Crimson => (220, 20, 60),
+            Color::Gold => (255, 215, 0),
+            Color::Indigo => (75, 0, 130),
+            Color::Coral => (255, 127, 80),
+            Color::Salmon => (250, 128, 114),
+            Color::Khaki => (240, 230, 140),
+            Color::Violet => (238, 130, 238),
+            Color::Rgb(r, g, b) => (*r, *g, *b),
+            Color::Rgba(r, g, b, a) => return (*r, *g, *b, a.clamp(0.0, 1.0)),
+            // Black if parsing fails; rendering reports the color through `validate`
+            Color::Hex(hex) => return parse_hex(hex).unwrap_or((0, 0, 0, 1.0)),
+            Color::Invalid(_) => (0, 0, 0),
+        };
+        (rgb.0, rgb.1, rgb.2, 1.0)
+    }
+
+    /// Opacity from 0.0 (transparent) to 1.0 (opaque)
+    pub fn alpha(&self) -> f32 {
+        self.to_rgba().3
+    }
+
+    /// The same color with its alpha replaced
+    pub fn with_alpha(&self, alpha: f32) -> Self {
+        let (r, g, b) = self.to_rgb();
+        Color::Rgba(r, g, b, alpha)
+    }
+
+    /// The same color with its alpha multiplied by `opacity`
+    pub fn with_opacity(&self, opacity: f32) -> Self {
+        self.with_alpha(self.alpha() * opacity)
+    }
+
+    pub fn to_hex_string(&self) -> String {
+        let (r, g, b) = self.to_rgb();
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    }
+    
+    pub fn to_rgb_string(&self) -> String {
+        let (r, g, b) = self.to_rgb();
+        format!("rgb({}, {}, {})", r, g, b)
+    }
+
+    pub fn to_rgba_string(&self) -> String {
+        let (r, g, b, a) = self.to_rgba();
+        format!("rgba({}, {}, {}, {})", r, g, b, a)
+    }
+    
+    /// Create an RGB color
+    pub fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color::Rgb(r, g, b)
+    }
+    
+    /// Create an RGB color with an alpha from 0.0 to 1.0
+    pub fn rgba(r: u8, g: u8, b: u8, a: f32) -> Self {
+        Color::Rgba(r, g, b, a)
+    }
+
+    /// Create a hex color
+    pub fn hex(hex_str: &str) -> Self {
+        Color::Hex(hex_str.to_string())
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from(rgb: (u8, u8, u8)) -> Self {
+        Color::Rgb(rgb.0, rgb.1, rgb.2)
+    }
+}
@@ -0,0 +1,320 @@
+use super::{
+    Color,
+    named::{css_color_names, css_named_color},
+};
+use std::{fmt, str::FromStr};
+
+/// Why a string could not be read as a color
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColorParseError {
+    /// Not a color name, with the closest known name when one is similar
+    UnknownName {
+        name: String,
+        suggestion: Option<&'static str>,
+    },
+    /// Hex digits of the wrong length or containing non-hex characters
+    InvalidHex(String),
+    /// A malformed `rgb()`, `rgba()`, `hsl()` or `hsla()` function
+    InvalidFunction(String),
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorParseError::UnknownName { name, suggestion } => {
+                write!(f, "unknown color name \"{name}\"")?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, " (did you mean \"{suggestion}\"?)")?;
+                }
+                Ok(())
+            }
+            ColorParseError::InvalidHex(hex) => write!(
+                f,
+                "invalid hex color \"{hex}\": expected 3, 4, 6 or 8 hex digits after an optional '#'"
+            ),
+            ColorParseError::InvalidFunction(function) => write!(
+                f,
+                "invalid color function \"{function}\": expected rgb(), rgba(), hsl() or hsla() \
+                 with 3 or 4 numeric arguments"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    /// Parses a color name, hex string or CSS color function.
+    ///
+    /// Names are case-insensitive and cover the variants of [`Color`] and every CSS named
+    /// color; names with a variant of the same RGB value map to it. `"darkgray"` and
+    /// `"lightgray"` follow CSS rather than [`Color::DarkGray`] and [`Color::LightGray`]. Hex colors
+    /// take 3, 4, 6 or 8 digits, with or without a leading `#`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.ends_with(')') {
+            parse_css_function(&s.to_lowercase())
+                .ok_or_else(|| ColorParseError::InvalidFunction(s.to_string()))
+        } else if s.starts_with('#')
+            || (matches!(s.len(), 3 | 4 | 6 | 8) && s.chars().all(|c| c.is_ascii_hexdigit()))
+        {
+            match parse_hex(s) {
+                Some(_) => Ok(Color::Hex(s.to_string())),
+                None => Err(ColorParseError::InvalidHex(s.to_string())),
+            }
+        } else {
+            let name = s.to_lowercase();
+            variant_named(&name)
+                .or_else(|| css_named_color(&name).map(Color::from))
+                .ok_or_else(|| ColorParseError::UnknownName {
+                    suggestion: closest_name(&name),
+                    name: s.to_string(),
+                })
+        }
+    }
+}
+
+/// Parses like [`FromStr`], keeping strings that are not colors as [`Color::Invalid`] so
+/// the chart reports them when rendered.
+impl From<&str> for Color {
+    fn from(s: &str) -> Self {
+        s.parse().unwrap_or_else(|_| Color::Invalid(s.to_string()))
+    }
+}
+
+impl From<String> for Color {
+    fn from(s: String) -> Self {
+        Color::from(s.as_str())
+    }
+}
+
+impl Color {
+    /// Checks that a `Hex` or `Invalid` color holds a parsable string; every other variant
+    /// is valid by construction.
+    pub fn validate(&self) -> Result<(), ColorParseError> {
+        match self {
+            Color::Hex(hex) if parse_hex(hex).is_none() => {
+                Err(ColorParseError::InvalidHex(hex.clone()))
+            }
+            Color::Invalid(s) => s.parse::<Color>().map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Checks every color a chart draws with, stopping at the first invalid one
+pub(crate) fn validate_colors<'a>(
+    colors: impl IntoIterator<Item = &'a Color>,
+) -> Result<(), ColorParseError> {
+    colors.into_iter().try_for_each(Color::validate)
+}
+
+/// The variant named `name`, which must be lowercase.
+///
+/// `DarkGray` and `LightGray` are left out: their RGB values differ from the CSS colors of
+/// the same names, which take precedence.
+fn variant_named(name: &str) -> Option<Color> {
+    let color = match name {
+        "black" => Color::Black,
+        "white" => Color::White,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "blue" => Color::Blue,
+        "yellow" => Color::Yellow,
+        "orange" => Color::Orange,
+        "purple" => Color::Purple,
+        "pink" => Color::Pink,
+        "brown" => Color::Brown,
+        "gray" | "grey" => Color::Gray,
+        "cyan" => Color::Cyan,
+        "magenta" => Color::Magenta,
+        "lime" => Color::Lime,
+        "navy" => Color::Navy,
+        "teal" => Color::Teal,
+        "silver" => Color::Silver,
+        "maroon" => Color::Maroon,
+        "olive" => Color::Olive,
+        "aqua" => Color::Aqua,
+        "fuchsia" => Color::Fuchsia,
+        "darkred" => Color::DarkRed,
+        "darkgreen" => Color::DarkGreen,
+        "darkblue" => Color::DarkBlue,
+        "lightred" => Color::LightRed,
+        "lightgreen" => Color::LightGreen,
+        "lightblue" => Color::LightBlue,
+        "crimson" => Color::Crimson,
+        "gold" => Color::Gold,
+        "indigo" => Color::Indigo,
+        "coral" => Color::Coral,
+        "salmon" => Color::Salmon,
+        "khaki" => Color::Khaki,
+        "violet" => Color::Violet,
+        "transparent" => Color::Rgba(0, 0, 0, 0.0),
+        _ => return None,
+    };
+    Some(color)
+}
+
+/// The known color name closest to `name`, if it is within a couple of typos
+fn closest_name(name: &str) -> Option<&'static str> {
+    css_color_names()
+        .chain(["lightred", "transparent"])
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= 2)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Parses `RGB`, `RGBA`, `RRGGBB` or `RRGGBBAA` hex digits, with or without a leading `#`
+pub(crate) fn parse_hex(hex: &str) -> Option<(u8, u8, u8, f32)> {
+    let hex = hex.trim().trim_start_matches('#');
+    // `from_str_radix` alone would also take a leading '+'
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let short = match hex.len() {
+        3 | 4 => true,
+        6 | 8 => false,
+        _ => return None,
+    };
+    let channel = |i: usize| {
+        let value = if short {
+            // Short form digits are doubled, so "f" means "ff"
+            u8::from_str_radix(&hex[i..=i], 16).ok()? * 17
+        } else {
+            u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).ok()?
+        };
+        Some(value)
+    };
+    let alpha = match hex.len() {
+        4 | 8 => channel(3)? as f32 / 255.0,
+        _ => 1.0,
+    };
+    Some((channel(0)?, channel(1)?, channel(2)?, alpha))
+}
+
+/// Parses the CSS functions `rgb()`, `rgba()`, `hsl()` and `hsla()`, in either the comma
+/// separated form or the space separated form with an optional `/ alpha`
+fn parse_css_function(s: &str) -> Option<Color> {
+    let (name, args) = s.strip_suffix(')')?.split_once('(')?;
+    let args: Vec<&str> = args
+        .split([',', '/', ' '])
+        .map(str::trim)
+        .filter(|arg| !arg.is_empty())
+        .collect();
+    if !(3..=4).contains(&args.len()) {
+        return None;
+    }
+    // Numbers, or percentages of `full`
+    let number = |arg: &str, full: f32| -> Option<f32> {
+        match arg.strip_suffix('%') {
+            Some(percent) => Some(percent.parse::<f32>().ok()? / 100.0 * full),
+            None => arg.parse::<f32>().ok(),
+        }
+    };
+    let alpha = match args.get(3) {
+        Some(arg) => number(arg, 1.0)?.clamp(0.0, 1.0),
+        None => 1.0,
+    };
+    let (r, g, b) = match name.trim() {
+        "rgb" | "rgba" => {
+            let channel = |arg: &str| number(arg, 255.0).map(|v| v.round().clamp(0.0, 255.0) as u8);
+            (channel(args[0])?, channel(args[1])?, channel(args[2])?)
+        }
+        "hsl" | "hsla" => {
+            let hue = args[0].strip_suffix("deg").unwrap_or(args[0]).parse::<f32>().ok()?;
+            let saturation = number(args[1], 1.0)?.clamp(0.0, 1.0);
+            let lightness = number(args[2], 1.0)?.clamp(0.0, 1.0);
+            Color::from_hsl(hue, saturation, lightness).to_rgb()
+        }
+        _ => return None,
+    };
+    Some(if alpha < 1.0 {
+        Color::Rgba(r, g, b, alpha)
+    } else {
+        Color::Rgb(r, g, b)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgba(s: &str) -> (u8, u8, u8, f32) {
+        s.parse::<Color>().unwrap().to_rgba()
+    }
+
+    #[test]
+    fn parses_names() {
+        assert_eq!("red".parse::<Color>(), Ok(Color::Red));
+        assert_eq!("Navy".parse::<Color>(), Ok(Color::Navy));
+        assert_eq!(rgba("rebeccapurple"), (102, 51, 153, 1.0));
+        assert_eq!(rgba("transparent").3, 0.0);
+    }
+
+    #[test]
+    fn gray_names_follow_css() {
+        assert_eq!(rgba("darkgray"), (169, 169, 169, 1.0));
+        assert_eq!(rgba("DarkGrey"), (169, 169, 169, 1.0));
+        assert_eq!(rgba("lightgray"), (211, 211, 211, 1.0));
+        assert_eq!(rgba("lightgrey"), (211, 211, 211, 1.0));
+        assert_eq!(rgba("gray"), (128, 128, 128, 1.0));
+    }
+
+    #[test]
+    fn parses_every_hex_length_with_and_without_prefix() {
+        for prefix in ["#", ""] {
+            assert_eq!(rgba(&format!("{prefix}f80")), (255, 136, 0, 1.0));
+            assert_eq!(rgba(&format!("{prefix}f800")), (255, 136, 0, 0.0));
+            assert_eq!(rgba(&format!("{prefix}ff8000")), (255, 128, 0, 1.0));
+            assert_eq!(rgba(&format!("{prefix}ff800080")), (255, 128, 0, 128.0 / 255.0));
+        }
+    }
+
+    #[test]
+    fn parses_css_functions() {
+        assert_eq!("rgb(255, 128, 0)".parse::<Color>(), Ok(Color::Rgb(255, 128, 0)));
+        assert_eq!("rgb(100% 50% 0%)".parse::<Color>(), Ok(Color::Rgb(255, 128, 0)));
+        assert_eq!("rgba(0, 0, 255, 0.5)".parse::<Color>(), Ok(Color::Rgba(0, 0, 255, 0.5)));
+        assert_eq!("rgb(0 0 255 / 50%)".parse::<Color>(), Ok(Color::Rgba(0, 0, 255, 0.5)));
+        assert_eq!("hsl(120, 100%, 50%)".parse::<Color>(), Ok(Color::Rgb(0, 255, 0)));
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(matches!("#12".parse::<Color>(), Err(ColorParseError::InvalidHex(_))));
+        assert!(matches!("#12345".parse::<Color>(), Err(ColorParseError::InvalidHex(_))));
+        assert!(matches!("#ggg".parse::<Color>(), Err(ColorParseError::InvalidHex(_))));
+        assert!(matches!("#+1+2+3".parse::<Color>(), Err(ColorParseError::InvalidHex(_))));
+        assert!(matches!("#+f+f".parse::<Color>(), Err(ColorParseError::InvalidHex(_))));
+        assert!("+f+f+f".parse::<Color>().is_err());
+        assert!(Color::Hex("#+1+2+3".to_string()).validate().is_err());
+        assert!(matches!("rgb(1, 2)".parse::<Color>(), Err(ColorParseError::InvalidFunction(_))));
+        assert!(matches!("rgb(a, b, c)".parse::<Color>(), Err(ColorParseError::InvalidFunction(_))));
+        assert_eq!(
+            "bleu".parse::<Color>(),
+            Err(ColorParseError::UnknownName {
+                name: "bleu".to_string(),
+                suggestion: Some("blue"),
+            })
+        );
+        assert!(Color::Invalid("nope".to_string()).validate().is_err());
+    }
+}
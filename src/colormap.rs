@@ -0,0 +1,95 @@
+use crate::color::Color;
+
+/// Continuous color scales used to map a normalized value onto a color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Colormap {
+    Viridis,
+    Plasma,
+    Inferno,
+    Magma,
+    Coolwarm,
+    Grayscale,
+}
+
+// Evenly spaced control points, interpolated linearly in RGB
+const VIRIDIS: [(u8, u8, u8); 9] = [
+    (68, 1, 84),
+    (71, 45, 123),
+    (59, 82, 139),
+    (44, 114, 142),
+    (33, 145, 140),
+    (40, 174, 128),
+    (94, 201, 98),
+    (173, 220, 48),
+    (253, 231, 37),
+];
+const PLASMA: [(u8, u8, u8); 9] = [
+    (13, 8, 135),
+    (76, 2, 161),
+    (126, 3, 168),
+    (169, 35, 149),
+    (204, 71, 120),
+    (229, 107, 93),
+    (248, 149, 64),
+    (253, 197, 39),
+    (240, 249, 33),
+];
+const INFERNO: [(u8, u8, u8); 9] = [
+    (0, 0, 4),
+    (31, 12, 72),
+    (85, 15, 109),
+    (136, 34, 106),
+    (186, 54, 85),
+    (227, 89, 51),
+    (249, 142, 9),
+    (249, 203, 53),
+    (252, 255, 164),
+];
+const MAGMA: [(u8, u8, u8); 9] = [
+    (0, 0, 4),
+    (28, 16, 68),
+    (79, 18, 123),
+    (129, 37, 129),
+    (181, 54, 122),
+    (229, 80, 100),
+    (251, 136, 97),
+    (254, 194, 135),
+    (252, 253, 191),
+];
+const COOLWARM: [(u8, u8, u8); 8] = [
+    (59, 76, 192),
+    (103, 136, 238),
+    (154, 187, 255),
+    (201, 215, 240),
+    (237, 209, 194),
+    (247, 168, 137),
+    (226, 105, 82),
+    (180, 4, 38),
+];
+const GRAYSCALE: [(u8, u8, u8); 2] = [(0, 0, 0), (255, 255, 255)];
+
+impl Colormap {
+    fn stops(&self) -> &'static [(u8, u8, u8)] {
+        match self {
+            Colormap::Viridis => &VIRIDIS,
+            Colormap::Plasma => &PLASMA,
+            Colormap::Inferno => &INFERNO,
+            Colormap::Magma => &MAGMA,
+            Colormap::Coolwarm => &COOLWARM,
+            Colormap::Grayscale => &GRAYSCALE,
+        }
+    }
+
+    /// Returns the color at position `t` along the scale, clamped to 0.0..=1.0
+    pub fn sample(&self, t: f32) -> Color {
+        let stops = self.stops();
+        let t = if t.is_finite() { t.clamp(0.0, 1.0) } else { 0.0 };
+        let position = t * (stops.len() - 1) as f32;
+        let index = (position.floor() as usize).min(stops.len() - 2);
+        let fraction = position - index as f32;
+        let (r0, g0, b0) = stops[index];
+        let (r1, g1, b1) = stops[index + 1];
+        let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * fraction).round() as u8;
+        Color::Rgb(lerp(r0, r1), lerp(g0, g1), lerp(b0, b1))
+    }
+}
@@ -1,89 +1,147 @@
 use crate::{
+    backend::{Backend, Path, Rect, Role, ShapeStyle, Stroke},
+    color::Color,
     traits::PlotValue,
     elements::{Interpolation, Line, Marker},
-    series::Series,
+    series::{Series, SeriesStyle},
 };
-use svg::node::element::{Group, Path, Rectangle};
 
 pub fn draw_data_series<T, Fx, Fy>(
+    backend: &mut dyn Backend,
     data: &[Series<T>],
+    styles: &[SeriesStyle],
     map_x: Fx,
     map_y: Fy,
-) -> Group
-where
+) where
     T: PlotValue,
     Fx: Fn(T) -> f32,
     Fy: Fn(T) -> f32,
 {
-    let mut data_group = Group::new().set("clip-path", "url(#plotAreaClip)");
-    for series in data {
-        let series_color_hex = series.color.to_hex_string();
+    for (index, (series, style)) in data.iter().zip(styles).enumerate() {
+        backend.set_role(Role::Series(index));
+        let baseline = series.baseline.unwrap_or(T::from_f32(0.0));
+        let screen_baseline = map_y(baseline);
 
-        // Draw lines/curves based on interpolation type
-        if series.line != Line::None && series.data.len() > 1 {
-            let line_path = match series.interpolation {
+        // Build the line/curve based on interpolation type
+        let line_data = if series.data.len() > 1 {
+            match series.interpolation {
                 Interpolation::Linear => draw_linear_path(series, &map_x, &map_y),
-                Interpolation::Step => draw_step_path(series, &map_x, &map_y),
+                Interpolation::Step | Interpolation::StepPre | Interpolation::StepMid => {
+                    draw_step_path(series, &map_x, &map_y, series.interpolation)
+                }
                 Interpolation::Bezier => draw_bezier_path(series, &map_x, &map_y),
                 Interpolation::Spline => draw_spline_path(series, &map_x, &map_y),
-            };
+            }
+        } else {
+            None
+        };
+
+        // Fill the area between the curve and the baseline
+        if let (Some(fill), Some(line_data), Some(first), Some(last)) = (
+            &series.fill,
+            &line_data,
+            series.data.first(),
+            series.data.last(),
+        ) {
+            let fill_data = line_data
+                .clone()
+                .line_to((map_x(last.0), screen_baseline))
+                .line_to((map_x(first.0), screen_baseline))
+                .close();
+            backend.path(&fill_data, &ShapeStyle::filled(fill.with_opacity(series.fill_opacity)));
+        }
 
-            if let Some(mut path) = line_path {
-                path = path
-                    .set("fill", "none")
-                    .set("stroke", series_color_hex.clone())
-                    .set("stroke-width", series.line_width);
-                if series.line == Line::Dashed {
-                    path = path.set("stroke-dasharray", "5 5");
+        let line_stroke = Stroke {
+            dash: line_dash_array(style.line),
+            ..Stroke::new(style.color.clone(), series.line_width)
+        };
+        // Stem series use the line style for their stems instead of a connecting line
+        if series.stem {
+            if let (Some(first), Some(last)) = (series.data.first(), series.data.last()) {
+                let baseline_data = Path::new()
+                    .move_to((map_x(first.0), screen_baseline))
+                    .line_to((map_x(last.0), screen_baseline));
+                let baseline_stroke = Stroke::new(style.color.clone(), series.line_width);
+                backend.path(&baseline_data, &ShapeStyle::stroked(baseline_stroke));
+            }
+            if style.line != Line::None {
+                let mut stem_data = Path::new();
+                for &(data_x, data_y) in &series.data {
+                    let screen_x = map_x(data_x);
+                    stem_data = stem_data
+                        .move_to((screen_x, screen_baseline))
+                        .line_to((screen_x, map_y(data_y)));
                 }
-                data_group = data_group.add(path);
+                backend.path(&stem_data, &ShapeStyle::stroked(line_stroke));
             }
+        } else if let (true, Some(line_data)) = (style.line != Line::None, line_data) {
+            backend.path(&line_data, &ShapeStyle::stroked(line_stroke));
         }
 
         // Draw markers
-        if series.marker != Marker::None {
+        if style.marker != Marker::None {
             let marker_size = series.marker_size;
             for &(data_x, data_y) in &series.data {
                 let screen_x = map_x(data_x);
                 let screen_y = map_y(data_y);
-                match series.marker {
-                    Marker::Circle => {
-                        let circle = svg::node::element::Circle::new()
-                            .set("cx", screen_x)
-                            .set("cy", screen_y)
-                            .set("r", marker_size / 2.0)
-                            .set("fill", series_color_hex.clone());
-                        data_group = data_group.add(circle);
-                    }
-                    Marker::Square => {
-                        let square = Rectangle::new()
-                            .set("x", screen_x - marker_size / 2.0)
-                            .set("y", screen_y - marker_size / 2.0)
-                            .set("width", marker_size)
-                            .set("height", marker_size)
-                            .set("fill", series_color_hex.clone());
-                        data_group = data_group.add(square);
-                    }
-                    Marker::Cross => {
-                        let d = marker_size / 2.0;
-                        let cross_data = svg::node::element::path::Data::new()
-                            .move_to((screen_x - d, screen_y - d))
-                            .line_to((screen_x + d, screen_y + d))
-                            .move_to((screen_x - d, screen_y + d))
-                            .line_to((screen_x + d, screen_y - d));
-                        let cross_path = Path::new()
-                            .set("d", cross_data)
-                            .set("stroke", series_color_hex.clone())
-                            .set("stroke-width", 1.0)
-                            .set("fill", "none");
-                        data_group = data_group.add(cross_path);
-                    }
-                    Marker::None => {}
-                }
+                draw_marker(
+                    backend,
+                    style.marker,
+                    marker_size,
+                    screen_x,
+                    screen_y,
+                    &style.color,
+                );
             }
         }
     }
-    data_group
+}
+
+/// Dash pattern for a series line style, empty for solid lines
+pub fn line_dash_array(line: Line) -> &'static [f32] {
+    match line {
+        Line::Dashed => &[5.0, 5.0],
+        Line::Dotted => &[1.0, 3.0],
+        Line::DashDot => &[8.0, 3.0, 1.0, 3.0],
+        Line::Solid | Line::None => &[],
+    }
+}
+
+/// Draws a single marker of the given shape centered on (screen_x, screen_y)
+pub fn draw_marker(
+    backend: &mut dyn Backend,
+    marker: Marker,
+    marker_size: f32,
+    screen_x: f32,
+    screen_y: f32,
+    color: &Color,
+) {
+    match marker {
+        Marker::Circle => {
+            let style = ShapeStyle::filled(color.clone());
+            backend.circle((screen_x, screen_y), marker_size / 2.0, &style);
+        }
+        Marker::Square => {
+            let square = Rect::new(
+                screen_x - marker_size / 2.0,
+                screen_y - marker_size / 2.0,
+                marker_size,
+                marker_size,
+            );
+            backend.rect(&square, &ShapeStyle::filled(color.clone()));
+        }
+        Marker::Cross => {
+            let d = marker_size / 2.0;
+            let cross_data = Path::new()
+                .move_to((screen_x - d, screen_y - d))
+                .line_to((screen_x + d, screen_y + d))
+                .move_to((screen_x - d, screen_y + d))
+                .line_to((screen_x + d, screen_y - d));
+            let style = ShapeStyle::stroked(Stroke::new(color.clone(), 1.0));
+            backend.path(&cross_data, &style);
+        }
+        Marker::None => {}
+    }
 }
 
 // Helper functions for different interpolation types
@@ -97,17 +155,22 @@ where
         return None;
     }
 
-    let mut line_data = svg::node::element::path::Data::new();
+    let mut line_data = Path::new();
     if let Some((first_x, first_y)) = series.data.first() {
         line_data = line_data.move_to((map_x(*first_x), map_y(*first_y)));
         for (x, y) in series.data.iter().skip(1) {
             line_data = line_data.line_to((map_x(*x), map_y(*y)));
         }
     }
-    Some(Path::new().set("d", line_data))
+    Some(line_data)
 }
 
-fn draw_step_path<T, Fx, Fy>(series: &Series<T>, map_x: &Fx, map_y: &Fy) -> Option<Path>
+fn draw_step_path<T, Fx, Fy>(
+    series: &Series<T>,
+    map_x: &Fx,
+    map_y: &Fy,
+    alignment: Interpolation,
+) -> Option<Path>
 where
     T: PlotValue,
     Fx: Fn(T) -> f32,
@@ -117,21 +180,35 @@ where
         return None;
     }
 
-    let mut line_data = svg::node::element::path::Data::new();
+    let mut line_data = Path::new();
     if let Some((first_x, first_y)) = series.data.first() {
         line_data = line_data.move_to((map_x(*first_x), map_y(*first_y)));
 
         for window in series.data.windows(2) {
-            let (_curr_x, curr_y) = window[0];
-            let (next_x, _next_y) = window[1];
+            let (curr_x, curr_y) = (map_x(window[0].0), map_y(window[0].1));
+            let (next_x, next_y) = (map_x(window[1].0), map_y(window[1].1));
 
-            // Draw horizontal line to next x position
-            line_data = line_data.line_to((map_x(next_x), map_y(curr_y)));
-            // Draw vertical line to next y position
-            line_data = line_data.line_to((map_x(next_x), map_y(window[1].1)));
+            line_data = match alignment {
+                // Vertical line at the current x, then horizontal to the next point
+                Interpolation::StepPre => line_data
+                    .line_to((curr_x, next_y))
+                    .line_to((next_x, next_y)),
+                // Change level halfway between the two points
+                Interpolation::StepMid => {
+                    let mid_x = (curr_x + next_x) / 2.0;
+                    line_data
+                        .line_to((mid_x, curr_y))
+                        .line_to((mid_x, next_y))
+                        .line_to((next_x, next_y))
+                }
+                // Horizontal line to the next x, then vertical to the next y
+                _ => line_data
+                    .line_to((next_x, curr_y))
+                    .line_to((next_x, next_y)),
+            };
         }
     }
-    Some(Path::new().set("d", line_data))
+    Some(line_data)
 }
 
 fn draw_bezier_path<T, Fx, Fy>(series: &Series<T>, map_x: &Fx, map_y: &Fy) -> Option<Path>
@@ -144,7 +221,7 @@ where
         return None;
     }
 
-    let mut line_data = svg::node::element::path::Data::new();
+    let mut line_data = Path::new();
     let points: Vec<(f32, f32)> = series
         .data
         .iter()
@@ -203,10 +280,10 @@ where
                     0.0
                 };
 
-            line_data = line_data.cubic_curve_to(((cp1_x, cp1_y), (cp2_x, cp2_y), next));
+            line_data = line_data.cubic_to((cp1_x, cp1_y), (cp2_x, cp2_y), next);
         }
     }
-    Some(Path::new().set("d", line_data))
+    Some(line_data)
 }
 
 fn draw_spline_path<T, Fx, Fy>(series: &Series<T>, map_x: &Fx, map_y: &Fy) -> Option<Path>
@@ -220,7 +297,7 @@ where
         return draw_linear_path(series, map_x, map_y);
     }
 
-    let mut line_data = svg::node::element::path::Data::new();
+    let mut line_data = Path::new();
     let points: Vec<(f32, f32)> = series
         .data
         .iter()
@@ -249,8 +326,8 @@ where
             let cp2_x = p2.0 - tension * (p3.0 - p1.0) / 6.0;
             let cp2_y = p2.1 - tension * (p3.1 - p1.1) / 6.0;
 
-            line_data = line_data.cubic_curve_to(((cp1_x, cp1_y), (cp2_x, cp2_y), p2));
+            line_data = line_data.cubic_to((cp1_x, cp1_y), (cp2_x, cp2_y), p2);
         }
     }
-    Some(Path::new().set("d", line_data))
+    Some(line_data)
 }
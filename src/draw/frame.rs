@@ -0,0 +1,180 @@
+use crate::{
+    backend::{Backend, Rect, Role, ShapeStyle},
+    color::{Color, validate_colors},
+    draw::{
+        LegendLayout, best_legend, draw_legend, draw_title, legend_layout, legend_origin,
+        legend_room,
+    },
+    elements::Legend,
+    error::Error,
+    legend_entry::LegendEntry,
+    style::*,
+};
+use std::iter::once;
+
+/// The settings every chart type shares: canvas, title, margins and legend.
+///
+/// Each chart borrows its own fields into a frame, which then checks their colors, reserves
+/// room for the legend and draws the background, title and legend the same way for all.
+pub(crate) struct ChartFrame<'c> {
+    pub dimensions: (i32, i32),
+    pub title: &'c str,
+    pub font: &'c str,
+    pub background: &'c Color,
+    pub margin: &'c Margin,
+    pub legend: Legend,
+    pub title_config: &'c TitleConfig,
+    pub legend_config: &'c LegendConfig,
+    pub color_cycle: &'c [Color],
+    pub legend_entries: &'c [LegendEntry<'c>],
+}
+
+/// Where the plot area and legend box of a chart go.
+pub(crate) struct ChartLayout {
+    pub legend_layout: LegendLayout,
+    /// Margins outside the plot area, without the room taken by an outside legend
+    pub margin: Margin,
+    pub plot_area: Rect,
+}
+
+impl<'c> ChartFrame<'c> {
+    /// Checks the shared colors followed by `colors`, the ones specific to the chart type
+    pub fn check_colors<'a>(
+        &'a self,
+        colors: impl IntoIterator<Item = &'a Color>,
+    ) -> Result<(), Error> {
+        let styles = [
+            self.background,
+            &self.title_config.color,
+            &self.legend_config.text_color,
+            &self.legend_config.border_color,
+            &self.legend_config.background,
+        ];
+        let colors = styles
+            .into_iter()
+            .chain(self.color_cycle)
+            .chain(colors)
+            .chain(self.legend_entries.iter().flat_map(|entry| once(&entry.color).chain(&entry.fill)));
+        Ok(validate_colors(colors)?)
+    }
+
+    /// Size of the legend box for `entries`, empty when the legend is hidden
+    pub fn legend_layout(&self, entries: &[LegendEntry]) -> LegendLayout {
+        if self.legend == Legend::None {
+            return LegendLayout::EMPTY;
+        }
+        let names: Vec<&str> = entries.iter().map(|entry| entry.name).collect();
+        legend_layout(
+            &names,
+            self.font,
+            self.legend_config,
+            self.legend,
+            self.dimensions.0 as f32 - self.margin.left - self.margin.right,
+        )
+    }
+
+    /// Extra (top, bottom, right) margin needed for a legend placed outside the plot area
+    pub fn legend_room(&self, legend_layout: &LegendLayout) -> (f32, f32, f32) {
+        legend_room(self.legend, self.legend_config, legend_layout)
+    }
+
+    /// Lays out the plot area inside `margin`, leaving room for an outside legend.
+    ///
+    /// Returns [`Error::PlotAreaTooSmall`] when nothing is left for the plot area.
+    pub fn layout(
+        &self,
+        margin: Margin,
+        legend_layout: LegendLayout,
+    ) -> Result<ChartLayout, Error> {
+        let (total_width, total_height) = self.dimensions;
+        let (room_top, room_bottom, room_right) = self.legend_room(&legend_layout);
+        let width = total_width as f32 - margin.left - margin.right - room_right;
+        let height = total_height as f32 - margin.top - margin.bottom - room_top - room_bottom;
+        if width <= 0.0 || height <= 0.0 {
+            return Err(Error::PlotAreaTooSmall { width, height });
+        }
+        let plot_area = Rect::new(margin.left, margin.top + room_top, width, height);
+        Ok(ChartLayout {
+            legend_layout,
+            margin,
+            plot_area,
+        })
+    }
+
+    /// Starts the drawing and fills the whole canvas with the background color
+    pub fn draw_background(&self, backend: &mut dyn Backend) {
+        let (total_width, total_height) = self.dimensions;
+        backend.begin(total_width as f32, total_height as f32);
+        backend.set_role(Role::Background);
+        let background = Rect::new(0.0, 0.0, total_width as f32, total_height as f32);
+        backend.rect(&background, &ShapeStyle::filled(self.background.clone()));
+    }
+
+    /// Draws the title centered over the plot area, in the top margin
+    pub fn draw_title(&self, backend: &mut dyn Backend, layout: &ChartLayout) {
+        draw_title(
+            backend,
+            self.title,
+            self.font,
+            self.title_config,
+            self.dimensions.0 as f32,
+            layout.plot_area.x,
+            layout.plot_area.width,
+            layout.margin.top,
+        );
+    }
+
+    /// Draws the legend for `entries`, if shown.
+    ///
+    /// `polylines` gives the screen coordinates of the drawn data, and is only called to pick
+    /// the placement for [`Legend::Best`].
+    pub fn draw_legend(
+        &self,
+        backend: &mut dyn Backend,
+        entries: &[LegendEntry],
+        layout: &ChartLayout,
+        polylines: impl FnOnce() -> Vec<Vec<(f32, f32)>>,
+    ) {
+        if self.legend == Legend::None || entries.is_empty() {
+            return;
+        }
+        let area = &layout.plot_area;
+        let legend_layout = &layout.legend_layout;
+        let legend = if self.legend == Legend::Best {
+            best_legend(
+                self.legend_config,
+                area.x,
+                area.y,
+                area.width,
+                area.height,
+                legend_layout.width,
+                legend_layout.height,
+                &polylines(),
+            )
+        } else {
+            self.legend
+        };
+        let (legend_x_base, legend_y_base) = legend_origin(
+            legend,
+            self.legend_config,
+            area.x,
+            area.y,
+            area.width,
+            area.height,
+            area.x + area.width,
+            layout.margin.top,
+            area.y + area.height + layout.margin.bottom,
+            legend_layout.width,
+            legend_layout.height,
+        );
+        draw_legend(
+            backend,
+            entries,
+            self.font,
+            self.legend_config,
+            legend_layout,
+            legend_x_base,
+            legend_y_base,
+        );
+    }
+}
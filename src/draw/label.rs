@@ -1,36 +1,41 @@
-use crate::style::*;
-use svg::Document;
-use svg::node::Text as SvgNodeText;
-use svg::node::element::Text;
+use crate::{
+    backend::{Backend, Role, Text, TextAnchor, TextBaseline, TextStyle},
+    style::*,
+    text::text_width,
+};
 
 pub fn draw_title(
-    document: Document,
+    backend: &mut dyn Backend,
     title: &str,
     font: &str,
     title_config: &TitleConfig,
+    total_width: f32,
     plot_area_x_start: f32,
     plot_area_width: f32,
     current_effective_margin_top: f32,
-) -> Document {
+) {
     if !title.is_empty() {
-        let title_text_x = plot_area_x_start + plot_area_width / 2.0;
+        // Centered over the plot area, but shifted to stay on the canvas when it is wider
+        let half_width = text_width(title, font, title_config.font_size) / 2.0;
+        let title_text_x = (plot_area_x_start + plot_area_width / 2.0)
+            .min(total_width - half_width)
+            .max(half_width.min(total_width / 2.0));
         let title_text_y = current_effective_margin_top * 0.5;
-        let title_svg = Text::new()
-            .set("x", title_text_x)
-            .set("y", title_text_y)
-            .set("font-family", font)
-            .set("font-size", title_config.font_size)
-            .set("fill", title_config.color.to_hex_string())
-            .set("text-anchor", "middle")
-            .set("dominant-baseline", "middle")
-            .add(SvgNodeText::new(title));
-        return document.add(title_svg);
+        let style = TextStyle {
+            font,
+            size: title_config.font_size,
+            color: title_config.color.clone(),
+            bold: false,
+            anchor: TextAnchor::Middle,
+            baseline: TextBaseline::Middle,
+        };
+        backend.set_role(Role::Title);
+        backend.text(&Text::new((title_text_x, title_text_y), title), &style);
     }
-    document
 }
 
 pub fn draw_x_label(
-    document: Document,
+    backend: &mut dyn Backend,
     x_label: &str,
     font: &str,
     x_label_config: &LabelConfig,
@@ -39,51 +44,55 @@ pub fn draw_x_label(
     plot_area_y_start: f32,
     plot_area_height: f32,
     current_effective_margin_bottom: f32,
-) -> Document {
+) {
     if !x_label.is_empty() {
         let x_label_text_x = plot_area_x_start + plot_area_width / 2.0;
         let x_label_text_y =
             plot_area_y_start + plot_area_height + current_effective_margin_bottom * 0.6;
-        let x_label_svg = Text::new()
-            .set("x", x_label_text_x)
-            .set("y", x_label_text_y)
-            .set("font-family", font)
-            .set("font-size", x_label_config.font_size)
-            .set("fill", x_label_config.color.to_hex_string())
-            .set("text-anchor", "middle")
-            .set("dominant-baseline", "middle")
-            .add(SvgNodeText::new(x_label));
-        return document.add(x_label_svg);
+        let style = TextStyle {
+            font,
+            size: x_label_config.font_size,
+            color: x_label_config.color.clone(),
+            bold: false,
+            anchor: TextAnchor::Middle,
+            baseline: TextBaseline::Middle,
+        };
+        backend.set_role(Role::AxisLabel);
+        backend.text(&Text::new((x_label_text_x, x_label_text_y), x_label), &style);
     }
-    document
 }
 
 pub fn draw_y_label(
-    document: Document,
+    backend: &mut dyn Backend,
     y_label: &str,
     font: &str,
     y_label_config: &LabelConfig,
     current_effective_margin_left: f32,
+    tick_label_extent: f32,
     plot_area_y_start: f32,
     plot_area_height: f32,
-) -> Document {
+) {
     if !y_label.is_empty() {
-        let y_label_text_x = current_effective_margin_left * 0.3;
+        // Keep clear of the tick labels, which extend `tick_label_extent` left of the plot area
+        let clear_of_ticks_x =
+            current_effective_margin_left - tick_label_extent - y_label_config.font_size * 0.8;
+        let y_label_text_x = (current_effective_margin_left * 0.3)
+            .min(clear_of_ticks_x)
+            .max(y_label_config.font_size * 0.6);
         let y_label_text_y = plot_area_y_start + plot_area_height / 2.0;
-        let y_label_svg = Text::new()
-            .set("x", y_label_text_x)
-            .set("y", y_label_text_y)
-            .set("font-family", font)
-            .set("font-size", y_label_config.font_size)
-            .set("fill", y_label_config.color.to_hex_string())
-            .set("text-anchor", "middle")
-            .set("dominant-baseline", "middle")
-            .set(
-                "transform",
-                format!("rotate(-90, {}, {})", y_label_text_x, y_label_text_y),
-            )
-            .add(SvgNodeText::new(y_label));
-        return document.add(y_label_svg);
+        let style = TextStyle {
+            font,
+            size: y_label_config.font_size,
+            color: y_label_config.color.clone(),
+            bold: false,
+            anchor: TextAnchor::Middle,
+            baseline: TextBaseline::Middle,
+        };
+        let text = Text {
+            rotation: -90.0,
+            ..Text::new((y_label_text_x, y_label_text_y), y_label)
+        };
+        backend.set_role(Role::AxisLabel);
+        backend.text(&text, &style);
     }
-    document
 }
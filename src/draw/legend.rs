@@ -1,57 +1,381 @@
 use crate::{
-    traits::PlotValue,
-    series::Series,
+    backend::{Backend, Rect, Role, ShapeStyle, Stroke, Text, TextAnchor, TextBaseline, TextStyle},
+    draw::{draw_marker, line_dash_array},
+    elements::{Legend, Line, Marker},
+    legend_entry::LegendEntry,
     style::*,
+    text::{max_text_width, text_width},
 };
-use svg::Document;
-use svg::node::Text as SvgNodeText;
-use svg::node::element::{Rectangle, Text};
 
-pub fn draw_legend<T: PlotValue>(
-    document: Document,
-    data: &[Series<T>],
+/// Size and grid arrangement of a legend box.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LegendLayout {
+    pub width: f32,
+    pub height: f32,
+    pub columns: usize,
+    pub column_width: f32,
+}
+
+impl LegendLayout {
+    pub const EMPTY: Self = Self {
+        width: 0.0,
+        height: 0.0,
+        columns: 0,
+        column_width: 0.0,
+    };
+}
+
+/// Arranges the legend entries into columns and computes the size of the legend box.
+///
+/// Entries fill rows left to right. Placements above or below the plot area use a
+/// horizontal strip with as many columns as fit in `max_width`; all others use
+/// `legend_config.columns`.
+pub fn legend_layout(
+    names: &[&str],
+    font: &str,
+    legend_config: &LegendConfig,
+    legend: Legend,
+    max_width: f32,
+) -> LegendLayout {
+    if names.is_empty() {
+        return LegendLayout::EMPTY;
+    }
+    let padding = legend_config.padding;
+    let spacing = legend_config.column_spacing;
+    let max_name_width = max_text_width(names.iter().copied(), font, legend_config.font_size);
+    let column_width =
+        legend_config.color_swatch_width + legend_config.text_offset + max_name_width;
+    let columns = match legend {
+        Legend::TopOutside | Legend::BottomOutside => {
+            ((max_width - padding * 2.0 + spacing) / (column_width + spacing)) as usize
+        }
+        _ => legend_config.columns,
+    }
+    .clamp(1, names.len());
+    let rows = names.len().div_ceil(columns);
+
+    let (title_width, title_height) = match &legend_config.title {
+        Some(title) => (
+            text_width(title, font, legend_config.font_size),
+            legend_config.item_height,
+        ),
+        None => (0.0, 0.0),
+    };
+    let entries_width = columns as f32 * column_width + (columns - 1) as f32 * spacing;
+    LegendLayout {
+        width: entries_width.max(title_width) + padding * 2.0,
+        height: title_height + rows as f32 * legend_config.item_height + padding * 2.0,
+        columns,
+        column_width,
+    }
+}
+
+/// Extra (top, bottom, right) margin needed for a legend placed outside the plot area.
+pub fn legend_room(legend: Legend, legend_config: &LegendConfig, layout: &LegendLayout) -> (f32, f32, f32) {
+    let padding = legend_config.padding;
+    match legend {
+        Legend::TopRightOutside | Legend::RightCenterOutside | Legend::BottomRightOutside => {
+            (0.0, 0.0, layout.width + padding)
+        }
+        // Leave a gap below the strip for the scale exponent label above the plot area
+        Legend::TopOutside => (layout.height + padding * 2.0, 0.0, 0.0),
+        Legend::BottomOutside => (0.0, layout.height + padding, 0.0),
+        _ => (0.0, 0.0, 0.0),
+    }
+}
+
+/// Computes the top-left corner of the legend box for the given placement.
+///
+/// `outside_x_start` is where the space reserved right of the plot area begins, and
+/// `outside_top_y` and `outside_bottom_y` are where the strips reserved above and below it begin.
+pub fn legend_origin(
+    legend: Legend,
+    legend_config: &LegendConfig,
+    plot_area_x_start: f32,
+    plot_area_y_start: f32,
+    plot_area_width: f32,
+    plot_area_height: f32,
+    outside_x_start: f32,
+    outside_top_y: f32,
+    outside_bottom_y: f32,
+    legend_width: f32,
+    legend_height: f32,
+) -> (f32, f32) {
+    let padding = legend_config.padding;
+    let right_inside_x = plot_area_x_start + plot_area_width - legend_width - padding;
+    let left_inside_x = plot_area_x_start + padding;
+    let outside_x = outside_x_start + padding;
+    let center_x = plot_area_x_start + (plot_area_width - legend_width) / 2.0;
+    let top_y = plot_area_y_start + padding;
+    let bottom_y = plot_area_y_start + plot_area_height - legend_height - padding;
+    let center_y = plot_area_y_start + (plot_area_height - legend_height) / 2.0;
+    match legend {
+        Legend::TopRightInside => (right_inside_x, top_y),
+        Legend::TopRightOutside => (outside_x, top_y),
+        Legend::BottomRightInside => (right_inside_x, bottom_y),
+        Legend::BottomRightOutside => (outside_x, bottom_y),
+        Legend::TopLeftInside => (left_inside_x, top_y),
+        Legend::BottomLeftInside => (left_inside_x, bottom_y),
+        Legend::RightCenterInside => (right_inside_x, center_y),
+        Legend::RightCenterOutside => (outside_x, center_y),
+        Legend::LeftCenterInside => (left_inside_x, center_y),
+        Legend::TopCenter => (center_x, top_y),
+        Legend::BottomCenter => (center_x, bottom_y),
+        Legend::TopOutside => (center_x.max(0.0), outside_top_y),
+        Legend::BottomOutside => (center_x.max(0.0), outside_bottom_y),
+        // Callers resolve Best with `best_legend`; without data it falls back to the top right
+        Legend::Best => (right_inside_x, top_y),
+        Legend::None => (0.0, 0.0),
+    }
+}
+
+/// Inside placements tried by `best_legend`, in order of preference when tied
+const BEST_CANDIDATES: [Legend; 8] = [
+    Legend::TopRightInside,
+    Legend::TopLeftInside,
+    Legend::BottomLeftInside,
+    Legend::BottomRightInside,
+    Legend::RightCenterInside,
+    Legend::LeftCenterInside,
+    Legend::BottomCenter,
+    Legend::TopCenter,
+];
+
+/// Picks the inside placement whose legend box overlaps the fewest data points and segments.
+///
+/// `polylines` holds the screen coordinates of each drawn line; single points count as markers.
+pub fn best_legend(
+    legend_config: &LegendConfig,
+    plot_area_x_start: f32,
+    plot_area_y_start: f32,
+    plot_area_width: f32,
+    plot_area_height: f32,
+    legend_width: f32,
+    legend_height: f32,
+    polylines: &[Vec<(f32, f32)>],
+) -> Legend {
+    let mut best = (Legend::TopRightInside, usize::MAX);
+    for candidate in BEST_CANDIDATES {
+        let (x, y) = legend_origin(
+            candidate,
+            legend_config,
+            plot_area_x_start,
+            plot_area_y_start,
+            plot_area_width,
+            plot_area_height,
+            0.0,
+            0.0,
+            0.0,
+            legend_width,
+            legend_height,
+        );
+        let rect = (x, y, x + legend_width, y + legend_height);
+        let mut overlaps = 0;
+        for line in polylines {
+            overlaps += line.iter().filter(|&&p| point_in_rect(p, rect)).count();
+            overlaps += line
+                .windows(2)
+                .filter(|w| segment_crosses_rect(w[0], w[1], rect))
+                .count();
+        }
+        if overlaps < best.1 {
+            best = (candidate, overlaps);
+        }
+        if overlaps == 0 {
+            break;
+        }
+    }
+    best.0
+}
+
+fn point_in_rect((x, y): (f32, f32), (x0, y0, x1, y1): (f32, f32, f32, f32)) -> bool {
+    x >= x0 && x <= x1 && y >= y0 && y <= y1
+}
+
+// Liang-Barsky clipping: true when any part of the segment lies within the rectangle
+fn segment_crosses_rect(
+    (ax, ay): (f32, f32),
+    (bx, by): (f32, f32),
+    (x0, y0, x1, y1): (f32, f32, f32, f32),
+) -> bool {
+    let (dx, dy) = (bx - ax, by - ay);
+    let (mut t0, mut t1) = (0.0f32, 1.0f32);
+    for (p, q) in [(-dx, ax - x0), (dx, x1 - ax), (-dy, ay - y0), (dy, y1 - ay)] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return false;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+            if t0 > t1 {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+pub fn draw_legend(
+    backend: &mut dyn Backend,
+    entries: &[LegendEntry],
     font: &str,
     legend_config: &LegendConfig,
+    layout: &LegendLayout,
     legend_x_base: f32,
     legend_y_base: f32,
-    legend_actual_box_width: f32,
-    legend_height: f32,
-) -> Document {
-    let legend_box_svg = Rectangle::new()
-        .set("x", legend_x_base)
-        .set("y", legend_y_base)
-        .set("width", legend_actual_box_width)
-        .set("height", legend_height)
-        .set("fill", "white")
-        .set("stroke", legend_config.border_color.to_hex_string())
-        .set("stroke-width", 1.0);
-    let mut document = document.add(legend_box_svg);
-    for (i, series) in data.iter().enumerate() {
-        let item_base_y =
-            legend_y_base + legend_config.padding + i as f32 * legend_config.item_height;
-        let swatch_x = legend_x_base + legend_config.padding;
-        let swatch_y =
-            item_base_y + (legend_config.item_height - legend_config.item_height * 0.8) / 2.0;
-        let color_hex = series.color.to_hex_string();
-        let swatch_svg = Rectangle::new()
-            .set("x", swatch_x)
-            .set("y", swatch_y)
-            .set("width", legend_config.color_swatch_width)
-            .set("height", legend_config.item_height * 0.8)
-            .set("fill", color_hex);
-        document = document.add(swatch_svg);
+) {
+    backend.set_role(Role::Legend);
+    let legend_box = Rect {
+        corner_radius: legend_config.corner_radius,
+        ..Rect::new(legend_x_base, legend_y_base, layout.width, layout.height)
+    };
+    let legend_box_style = ShapeStyle {
+        fill: Some(legend_config.background.with_opacity(legend_config.background_opacity)),
+        stroke: legend_config
+            .show_border
+            .then(|| Stroke::new(legend_config.border_color.clone(), 1.0)),
+    };
+    backend.rect(&legend_box, &legend_box_style);
+
+    let mut entries_y_base = legend_y_base + legend_config.padding;
+    if let Some(title) = &legend_config.title {
+        let title_style = TextStyle {
+            font,
+            size: legend_config.font_size,
+            color: legend_config.text_color.clone(),
+            bold: true,
+            anchor: TextAnchor::Middle,
+            baseline: TextBaseline::Middle,
+        };
+        let title_position = (
+            legend_x_base + layout.width / 2.0,
+            entries_y_base + legend_config.item_height / 2.0,
+        );
+        backend.text(&Text::new(title_position, title), &title_style);
+        entries_y_base += legend_config.item_height;
+    }
+
+    let text_style = TextStyle {
+        font,
+        size: legend_config.font_size,
+        color: legend_config.text_color.clone(),
+        bold: false,
+        anchor: TextAnchor::Start,
+        baseline: TextBaseline::Middle,
+    };
+    let columns = layout.columns.max(1);
+    for (i, entry) in entries.iter().enumerate() {
+        let (row, column) = (i / columns, i % columns);
+        let item_base_y = entries_y_base + row as f32 * legend_config.item_height;
+        let swatch_x = legend_x_base
+            + legend_config.padding
+            + column as f32 * (layout.column_width + legend_config.column_spacing);
+        draw_legend_glyph(
+            backend,
+            entry,
+            swatch_x,
+            item_base_y,
+            legend_config.color_swatch_width,
+            legend_config.item_height,
+        );
         let text_x = swatch_x + legend_config.color_swatch_width + legend_config.text_offset;
         let text_y = item_base_y + legend_config.item_height / 2.0;
-        let legend_text_svg = Text::new()
-            .set("x", text_x)
-            .set("y", text_y)
-            .set("font-family", font)
-            .set("font-size", legend_config.font_size)
-            .set("fill", legend_config.text_color.to_hex_string())
-            .set("text-anchor", "start")
-            .set("dominant-baseline", "middle")
-            .add(SvgNodeText::new(series.name));
-        document = document.add(legend_text_svg);
-    }
-    document
+        backend.text(&Text::new((text_x, text_y), entry.name), &text_style);
+    }
+}
+
+/// Draws the line, fill and marker sample for an entry within its swatch area
+fn draw_legend_glyph(
+    backend: &mut dyn Backend,
+    entry: &LegendEntry,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+) {
+    let swatch_height = height * 0.8;
+    let swatch_y = y + (height - swatch_height) / 2.0;
+    let center_y = y + height / 2.0;
+    let swatch = Rect::new(x, swatch_y, width, swatch_height);
+    backend.begin_group();
+
+    let has_sample = entry.line != Line::None || entry.marker != Marker::None;
+    match &entry.fill {
+        Some(fill) => {
+            let style = ShapeStyle::filled(fill.with_opacity(entry.fill_opacity));
+            backend.rect(&swatch, &style);
+        }
+        // Entries with nothing else to show keep the plain color block
+        None if !has_sample => backend.rect(&swatch, &ShapeStyle::filled(entry.color.clone())),
+        None => {}
+    }
+    if entry.line != Line::None {
+        let stroke = Stroke {
+            dash: line_dash_array(entry.line),
+            ..Stroke::new(entry.color.clone(), entry.line_width)
+        };
+        backend.line((x, center_y), (x + width, center_y), &stroke);
+    }
+    // Markers are drawn at their plotted size, up to the swatch height
+    let marker_size = entry.marker_size.min(swatch_height);
+    draw_marker(backend, entry.marker, marker_size, x + width / 2.0, center_y, &entry.color);
+    backend.end_group();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECT: (f32, f32, f32, f32) = (10.0, 10.0, 20.0, 20.0);
+
+    #[test]
+    fn segment_through_rect_crosses() {
+        assert!(segment_crosses_rect((0.0, 15.0), (30.0, 15.0), RECT));
+        assert!(segment_crosses_rect((0.0, 0.0), (30.0, 30.0), RECT));
+    }
+
+    #[test]
+    fn segment_ending_inside_crosses() {
+        assert!(segment_crosses_rect((0.0, 15.0), (15.0, 15.0), RECT));
+        assert!(segment_crosses_rect((12.0, 12.0), (18.0, 18.0), RECT));
+    }
+
+    #[test]
+    fn segment_beside_rect_does_not_cross() {
+        assert!(!segment_crosses_rect((0.0, 5.0), (30.0, 5.0), RECT));
+        assert!(!segment_crosses_rect((25.0, 0.0), (25.0, 30.0), RECT));
+        // Passes the corner diagonally without entering
+        assert!(!segment_crosses_rect((0.0, 19.0), (19.0, 0.0), RECT));
+    }
+
+    fn best(polylines: &[Vec<(f32, f32)>]) -> Legend {
+        let config = LegendConfig::default();
+        best_legend(&config, 0.0, 0.0, 400.0, 300.0, 80.0, 40.0, polylines)
+    }
+
+    #[test]
+    fn best_legend_prefers_top_right_when_empty() {
+        assert_eq!(best(&[]), Legend::TopRightInside);
+    }
+
+    #[test]
+    fn best_legend_avoids_rising_line() {
+        // A line rising to the top right leaves the top left free
+        let line = vec![(0.0, 300.0), (400.0, 0.0)];
+        assert_eq!(best(&[line]), Legend::TopLeftInside);
+    }
+
+    #[test]
+    fn best_legend_avoids_markers() {
+        let markers: Vec<Vec<(f32, f32)>> = [(370.0, 20.0), (20.0, 20.0)]
+            .into_iter()
+            .map(|p| vec![p])
+            .collect();
+        assert_eq!(best(&markers), Legend::BottomLeftInside);
+    }
 }
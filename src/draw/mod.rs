@@ -1,13 +1,31 @@
-// Drawing helper functions for Plot SVG rendering
+// Drawing helper functions that emit chart elements to a backend
+#![allow(clippy::too_many_arguments)]
 
 mod axis;
 mod data_series;
+mod frame;
 mod label;
 mod legend;
+mod ohlc;
+mod paint;
+mod pie;
+mod polar;
 mod ticks_and_grids;
+mod vector_field;
 
 pub use axis::draw_axis_lines;
-pub use data_series::draw_data_series;
+pub use data_series::{draw_data_series, draw_marker, line_dash_array};
+pub(crate) use frame::ChartFrame;
 pub use label::{draw_title, draw_x_label, draw_y_label};
-pub use legend::draw_legend;
-pub use ticks_and_grids::draw_ticks_and_grids;
+pub use legend::{
+    LegendLayout, best_legend, draw_legend, legend_layout, legend_origin, legend_room,
+};
+pub use ohlc::draw_ohlc_series;
+pub use paint::Paint;
+pub use pie::{draw_pie, slice_label_text};
+pub use polar::{draw_polar_grid, draw_polar_series};
+pub use ticks_and_grids::{
+    calculate_linear_ticks, calculate_log_ticks, draw_ticks_and_grids, format_pi_value,
+    grid_dash_array, major_ticks, scale_exponent, tick_label, tick_label_width,
+};
+pub use vector_field::draw_vector_fields;
@@ -0,0 +1,104 @@
+use crate::{
+    backend::{Backend, Path, Rect, Role, ShapeStyle, Stroke},
+    traits::PlotValue,
+    elements::OhlcStyle,
+    ohlc::OhlcSeries,
+};
+
+pub fn draw_ohlc_series<T, Fx, Fy>(
+    backend: &mut dyn Backend,
+    data: &[OhlcSeries<T>],
+    map_x: &Fx,
+    map_y: &Fy,
+    plot_area_y_start: f32,
+    plot_area_height: f32,
+) where
+    T: PlotValue,
+    Fx: Fn(T) -> f32,
+    Fy: Fn(T) -> f32,
+{
+    let plot_area_bottom = plot_area_y_start + plot_area_height;
+    for (index, series) in data.iter().enumerate() {
+        backend.set_role(Role::Ohlc(index));
+        let screen_times: Vec<f32> = series.data.iter().map(|&(t, ..)| map_x(t)).collect();
+
+        // Body width follows the tightest spacing between consecutive periods
+        let min_spacing = screen_times
+            .windows(2)
+            .map(|w| (w[1] - w[0]).abs())
+            .filter(|d| *d > 0.0)
+            .fold(f32::INFINITY, f32::min);
+        let spacing = if min_spacing.is_finite() { min_spacing } else { 20.0 };
+        let body_width = (spacing * series.body_width).max(1.0);
+
+        // Volume bars share the bottom of the plot area and are scaled to the largest volume
+        if let Some(volume) = &series.volume {
+            let max_volume = volume
+                .iter()
+                .map(|v| v.to_f32())
+                .fold(0.0f32, f32::max);
+            if max_volume > 0.0 {
+                for ((&(_, open, _, _, close), &v), &screen_x) in
+                    series.data.iter().zip(volume.iter()).zip(screen_times.iter())
+                {
+                    let bar_height =
+                        v.to_f32().max(0.0) / max_volume * series.volume_height * plot_area_height;
+                    let color = if close >= open {
+                        &series.up_color
+                    } else {
+                        &series.down_color
+                    };
+                    let volume_bar = Rect::new(
+                        screen_x - body_width / 2.0,
+                        plot_area_bottom - bar_height,
+                        body_width,
+                        bar_height,
+                    );
+                    backend.rect(&volume_bar, &ShapeStyle::filled(color.with_opacity(0.3)));
+                }
+            }
+        }
+
+        for (&(_, open, high, low, close), &screen_x) in
+            series.data.iter().zip(screen_times.iter())
+        {
+            let color = if close >= open {
+                &series.up_color
+            } else {
+                &series.down_color
+            };
+            let (screen_open, screen_high) = (map_y(open), map_y(high));
+            let (screen_low, screen_close) = (map_y(low), map_y(close));
+            let stroke = Stroke::new(color.clone(), series.line_width);
+
+            match series.style {
+                OhlcStyle::Candlestick => {
+                    let wick = Path::new()
+                        .move_to((screen_x, screen_high))
+                        .line_to((screen_x, screen_low));
+                    backend.path(&wick, &ShapeStyle::stroked(stroke.clone()));
+
+                    let body_top = screen_open.min(screen_close);
+                    let body_height = (screen_open - screen_close).abs().max(series.line_width);
+                    let body = Rect::new(screen_x - body_width / 2.0, body_top, body_width, body_height);
+                    let style = ShapeStyle {
+                        fill: Some(color.clone()),
+                        stroke: Some(stroke),
+                    };
+                    backend.rect(&body, &style);
+                }
+                OhlcStyle::Bar => {
+                    let half = body_width / 2.0;
+                    let bar = Path::new()
+                        .move_to((screen_x, screen_high))
+                        .line_to((screen_x, screen_low))
+                        .move_to((screen_x - half, screen_open))
+                        .line_to((screen_x, screen_open))
+                        .move_to((screen_x, screen_close))
+                        .line_to((screen_x + half, screen_close));
+                    backend.path(&bar, &ShapeStyle::stroked(stroke));
+                }
+            }
+        }
+    }
+}
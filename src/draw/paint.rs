@@ -0,0 +1,31 @@
+use crate::color::Color;
+use svg::Node;
+
+/// Sets fill and stroke colors on SVG elements, adding `fill-opacity` and `stroke-opacity`
+/// for translucent colors so the alpha survives in every SVG renderer.
+pub trait Paint: Node + Sized {
+    fn fill_color(self, color: &Color) -> Self {
+        self.fill_color_with_opacity(color, 1.0)
+    }
+
+    /// Fills with `color` at `opacity`, which is combined with the color's own alpha
+    fn fill_color_with_opacity(mut self, color: &Color, opacity: f32) -> Self {
+        self.assign("fill", color.to_hex_string());
+        let opacity = opacity * color.alpha();
+        if opacity < 1.0 {
+            self.assign("fill-opacity", opacity);
+        }
+        self
+    }
+
+    fn stroke_color(mut self, color: &Color) -> Self {
+        self.assign("stroke", color.to_hex_string());
+        let opacity = color.alpha();
+        if opacity < 1.0 {
+            self.assign("stroke-opacity", opacity);
+        }
+        self
+    }
+}
+
+impl<N: Node + Sized> Paint for N {}
@@ -0,0 +1,176 @@
+use crate::{
+    backend::{Backend, Path, Role, ShapeStyle, Stroke, Text, TextAnchor, TextBaseline, TextStyle},
+    color::Color,
+    elements::{Direction, LabelPosition, SliceLabel},
+    slice::Slice,
+    style::*,
+};
+use std::f32::consts::PI;
+
+/// Returns the text shown next to a slice for the given label mode.
+pub fn slice_label_text(slice: &Slice, total: f64, slice_label: SliceLabel) -> String {
+    match slice_label {
+        SliceLabel::Percent => format!("{:.1}%", slice.value / total * 100.0),
+        SliceLabel::Value => format!("{}", slice.value),
+        SliceLabel::Name => slice.name.to_string(),
+        SliceLabel::None => String::new(),
+    }
+}
+
+// Screen position of a point at `angle` (radians, counter-clockwise from 3 o'clock) and radius `r`
+fn polar_point(cx: f32, cy: f32, r: f32, angle: f32) -> (f32, f32) {
+    (cx + r * angle.cos(), cy - r * angle.sin())
+}
+
+fn slice_path(
+    cx: f32,
+    cy: f32,
+    radius: f32,
+    inner_radius: f32,
+    start: f32,
+    end: f32,
+    direction: Direction,
+) -> Path {
+    let span = (end - start).abs();
+    let large_arc = span > PI;
+    // Sweeping draws in the clockwise screen direction
+    let sweep = direction == Direction::Clockwise;
+    let reverse_sweep = !sweep;
+
+    // A full circle cannot be expressed as a single arc, so split it in two halves
+    if span >= 2.0 * PI - 1e-4 {
+        let mid = start + (end - start) / 2.0;
+        let (ox0, oy0) = polar_point(cx, cy, radius, start);
+        let (ox1, oy1) = polar_point(cx, cy, radius, mid);
+        let mut data = Path::new()
+            .move_to((ox0, oy0))
+            .arc_to(radius, false, sweep, (ox1, oy1))
+            .arc_to(radius, false, sweep, (ox0, oy0))
+            .close();
+        if inner_radius > 0.0 {
+            let (ix0, iy0) = polar_point(cx, cy, inner_radius, start);
+            let (ix1, iy1) = polar_point(cx, cy, inner_radius, mid);
+            data = data
+                .move_to((ix0, iy0))
+                .arc_to(inner_radius, false, reverse_sweep, (ix1, iy1))
+                .arc_to(inner_radius, false, reverse_sweep, (ix0, iy0))
+                .close();
+        }
+        return data;
+    }
+
+    let (ox0, oy0) = polar_point(cx, cy, radius, start);
+    let (ox1, oy1) = polar_point(cx, cy, radius, end);
+    let data = Path::new()
+        .move_to((ox0, oy0))
+        .arc_to(radius, large_arc, sweep, (ox1, oy1));
+    if inner_radius > 0.0 {
+        let (ix0, iy0) = polar_point(cx, cy, inner_radius, start);
+        let (ix1, iy1) = polar_point(cx, cy, inner_radius, end);
+        data.line_to((ix1, iy1))
+            .arc_to(inner_radius, large_arc, reverse_sweep, (ix0, iy0))
+            .close()
+    } else {
+        data.line_to((cx, cy)).close()
+    }
+}
+
+pub fn draw_pie(
+    backend: &mut dyn Backend,
+    slices: &[Slice],
+    colors: &[Color],
+    font: &str,
+    pie_config: &PieConfig,
+    slice_label: SliceLabel,
+    label_position: LabelPosition,
+    cx: f32,
+    cy: f32,
+    radius: f32,
+    inner_radius: f32,
+    start_angle: f32,
+    direction: Direction,
+) {
+    let total: f64 = slices.iter().map(|s| s.value.max(0.0)).sum();
+    if total <= 0.0 {
+        return;
+    }
+    let inner_radius = radius * inner_radius.clamp(0.0, 0.95);
+    let sign = match direction {
+        Direction::Clockwise => -1.0,
+        Direction::CounterClockwise => 1.0,
+    };
+
+    let label_style = TextStyle {
+        font,
+        size: pie_config.label_font_size,
+        color: pie_config.label_color.clone(),
+        bold: false,
+        anchor: TextAnchor::Middle,
+        baseline: TextBaseline::Middle,
+    };
+
+    let mut current_angle = start_angle.to_radians();
+    for (index, (slice, color)) in slices.iter().zip(colors.iter()).enumerate() {
+        let value = slice.value.max(0.0);
+        if value == 0.0 {
+            continue;
+        }
+        let span = (value / total) as f32 * 2.0 * PI;
+        let start = current_angle;
+        let end = current_angle + sign * span;
+        let mid = (start + end) / 2.0;
+        current_angle = end;
+
+        // Exploded slices are shifted outward along their bisector
+        let (scx, scy) = polar_point(cx, cy, radius * slice.explode.max(0.0), mid);
+
+        let style = ShapeStyle {
+            fill: Some(color.clone()),
+            stroke: Some(Stroke::new(pie_config.border_color.clone(), pie_config.border_width)),
+        };
+        let path = slice_path(scx, scy, radius, inner_radius, start, end, direction);
+        backend.set_role(Role::Slice(index));
+        backend.path(&path, &style);
+
+        let text = slice_label_text(slice, total, slice_label);
+        if text.is_empty() {
+            continue;
+        }
+        backend.set_role(Role::SliceLabel);
+        match label_position {
+            LabelPosition::Inside => {
+                // Center the label in the ring for donuts, or at 60% of the radius for pies
+                let label_radius = if inner_radius > 0.0 {
+                    (radius + inner_radius) / 2.0
+                } else {
+                    radius * 0.6
+                };
+                let (tx, ty) = polar_point(scx, scy, label_radius, mid);
+                backend.text(&Text::new((tx, ty), &text), &label_style);
+            }
+            LabelPosition::Outside => {
+                let (x0, y0) = polar_point(scx, scy, radius, mid);
+                let (x1, y1) = polar_point(scx, scy, radius + pie_config.leader_length, mid);
+                let right_side = mid.cos() >= 0.0;
+                let elbow = if right_side {
+                    pie_config.leader_length * 0.6
+                } else {
+                    -pie_config.leader_length * 0.6
+                };
+                let x2 = x1 + elbow;
+                let leader = Path::new().move_to((x0, y0)).line_to((x1, y1)).line_to((x2, y1));
+                let leader_stroke = Stroke::new(pie_config.leader_color.clone(), 1.0);
+                backend.path(&leader, &ShapeStyle::stroked(leader_stroke));
+                let anchor_dot = ShapeStyle::filled(pie_config.leader_color.clone());
+                backend.circle((x0, y0), 1.5, &anchor_dot);
+
+                let style = TextStyle {
+                    anchor: if right_side { TextAnchor::Start } else { TextAnchor::End },
+                    ..label_style.clone()
+                };
+                let position = (x2 + if right_side { 3.0 } else { -3.0 }, y1);
+                backend.text(&Text::new(position, &text), &style);
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    backend::{Backend, Path, Role, ShapeStyle, Stroke, Text, TextAnchor, TextBaseline, TextStyle},
+    traits::PlotValue,
+    draw::{draw_marker, format_pi_value, grid_dash_array, line_dash_array},
+    elements::{AngleUnit, Grid, Line, Marker},
+    series::{Series, SeriesStyle},
+    style::*,
+};
+use std::f32::consts::PI;
+
+/// Formats an angle given in radians for an angular tick label
+fn format_angle(theta: f32, angle_unit: AngleUnit) -> String {
+    match angle_unit {
+        AngleUnit::Degrees => format!("{}°", theta.to_degrees().round() as i32),
+        AngleUnit::Radians => format_pi_value(theta),
+    }
+}
+
+pub fn draw_polar_grid<FA, FR>(
+    backend: &mut dyn Backend,
+    grid: Grid,
+    grid_config: &GridConfig,
+    axis_config: &AxisConfig,
+    tick_config: &TickConfig,
+    font: &str,
+    cx: f32,
+    cy: f32,
+    radius: f32,
+    radial_ticks: &[f32],
+    angular_divisions: usize,
+    angle_unit: AngleUnit,
+    map_angle: FA,
+    map_r: FR,
+) where
+    FA: Fn(f32) -> f32,
+    FR: Fn(f32) -> f32,
+{
+    let divisions = angular_divisions.max(1);
+    let angle_step = 2.0 * PI / divisions as f32;
+    let grid_stroke = Stroke {
+        dash: grid_dash_array(grid),
+        ..Stroke::new(grid_config.color.clone(), grid_config.line_width)
+    };
+    let label_style = TextStyle {
+        font,
+        size: tick_config.font_size,
+        color: tick_config.label_color.clone(),
+        bold: false,
+        anchor: TextAnchor::Middle,
+        baseline: TextBaseline::Middle,
+    };
+
+    // Concentric circles at the radial ticks
+    if grid != Grid::None {
+        for &tick_val in radial_ticks {
+            let r = map_r(tick_val);
+            if r <= 0.1 || r >= radius - 0.1 {
+                continue;
+            }
+            backend.set_role(Role::Grid);
+            backend.circle((cx, cy), r, &ShapeStyle::stroked(grid_stroke.clone()));
+        }
+    }
+
+    // Spokes and angular labels
+    let label_radius = radius + tick_config.text_padding + tick_config.font_size;
+    for i in 0..divisions {
+        let theta = i as f32 * angle_step;
+        let screen_angle = map_angle(theta);
+        let (dx, dy) = (screen_angle.cos(), -screen_angle.sin());
+        if grid != Grid::None {
+            backend.set_role(Role::Grid);
+            backend.line((cx, cy), (cx + radius * dx, cy + radius * dy), &grid_stroke);
+        }
+        let label = format_angle(theta, angle_unit);
+        let position = (cx + label_radius * dx, cy + label_radius * dy);
+        backend.set_role(Role::TickLabel);
+        backend.text(&Text::new(position, &label), &label_style);
+    }
+
+    // Radial tick labels run along the ray halfway between the first two spokes
+    let label_angle = map_angle(angle_step / 2.0);
+    let (dx, dy) = (label_angle.cos(), -label_angle.sin());
+    let radial_style = TextStyle {
+        anchor: TextAnchor::Start,
+        ..label_style
+    };
+    for &tick_val in radial_ticks {
+        let r = map_r(tick_val);
+        if r < 0.0 || r > radius + 0.1 {
+            continue;
+        }
+        let label = format!("{:.1}", tick_val);
+        let position = (cx + r * dx + tick_config.text_padding, cy + r * dy);
+        backend.set_role(Role::TickLabel);
+        backend.text(&Text::new(position, &label), &radial_style);
+    }
+
+    // Outer boundary
+    backend.set_role(Role::Axis);
+    let boundary = Stroke::new(axis_config.color.clone(), axis_config.line_width);
+    backend.circle((cx, cy), radius, &ShapeStyle::stroked(boundary));
+}
+
+pub fn draw_polar_series<T, F>(
+    backend: &mut dyn Backend,
+    data: &[Series<T>],
+    styles: &[SeriesStyle],
+    map_point: F,
+) where
+    T: PlotValue,
+    F: Fn(T, T) -> (f32, f32),
+{
+    for (index, (series, style)) in data.iter().zip(styles).enumerate() {
+        backend.set_role(Role::Series(index));
+        let points: Vec<(f32, f32)> = series
+            .data
+            .iter()
+            .map(|&(theta, r)| map_point(theta, r))
+            .collect();
+        if points.is_empty() {
+            continue;
+        }
+
+        let mut line_data = Path::new().move_to(points[0]);
+        for &point in points.iter().skip(1) {
+            line_data = line_data.line_to(point);
+        }
+
+        if let Some(fill) = &series.fill {
+            let style = ShapeStyle::filled(fill.with_opacity(series.fill_opacity));
+            backend.path(&line_data.clone().close(), &style);
+        }
+
+        // Polar series are always joined with straight segments in screen space
+        if style.line != Line::None && points.len() > 1 {
+            let stroke = Stroke {
+                dash: line_dash_array(style.line),
+                ..Stroke::new(style.color.clone(), series.line_width)
+            };
+            backend.path(&line_data, &ShapeStyle::stroked(stroke));
+        }
+
+        if style.marker != Marker::None {
+            for &(screen_x, screen_y) in &points {
+                draw_marker(
+                    backend,
+                    style.marker,
+                    series.marker_size,
+                    screen_x,
+                    screen_y,
+                    &style.color,
+                );
+            }
+        }
+    }
+}
@@ -1,8 +1,99 @@
+use crate::backend::{Backend, Point, Role, Stroke, Text, TextAnchor, TextBaseline, TextStyle};
 use crate::elements::{Axis, Grid, Scale, Tick, MinorGrid};
 use crate::style::*;
-use svg::Document;
-use svg::node::Text as SvgNodeText;
-use svg::node::element::{Line as SvgLine, Text};
+use crate::text::text_width;
+
+/// Calculates evenly spaced "nice" tick values (steps of 1, 2 or 5 × 10ⁿ) covering the range
+pub fn calculate_linear_ticks(min_val: f32, max_val: f32, max_ticks: usize) -> Vec<f32> {
+    if (max_val - min_val).abs() < f32::EPSILON {
+        return vec![min_val];
+    }
+    let range = max_val - min_val;
+    let rough_step = range / (max_ticks.saturating_sub(1) as f32).max(1.0);
+    if rough_step == 0.0 {
+        return vec![min_val];
+    }
+    let exponent = rough_step.log10().floor();
+    let fraction = rough_step / 10f32.powf(exponent);
+    let nice_fraction = if fraction < 1.5 {
+        1.0
+    } else if fraction < 3.5 {
+        2.0
+    } else if fraction < 7.5 {
+        5.0
+    } else {
+        10.0
+    };
+    let step = nice_fraction * 10f32.powf(exponent);
+    if step == 0.0 {
+        return vec![min_val, max_val].into_iter().collect();
+    }
+
+    let start_tick = (min_val / step).floor() * step;
+    let mut ticks = Vec::new();
+    let mut current_tick = start_tick;
+
+    while current_tick <= max_val + step * 0.5 {
+        if current_tick >= min_val - step * 0.1 && current_tick <= max_val + step * 0.1 {
+            ticks.push(current_tick);
+        }
+        current_tick += step;
+        if ticks.len() > max_ticks * 2 {
+            break;
+        }
+    }
+
+    if ticks.is_empty() {
+        if min_val == max_val {
+            ticks.push(min_val);
+        } else {
+            ticks.extend_from_slice(&[min_val, max_val]);
+        }
+    } else if ticks.len() == 1 && min_val != max_val {
+        ticks.push(max_val);
+    }
+    ticks
+}
+
+/// Calculates power-of-ten tick values covering the range
+pub fn calculate_log_ticks(min_val: f32, max_val: f32) -> Vec<f32> {
+    // Handle cases where min_val is 0 or negative by using a small positive value
+    let safe_min_val = if min_val <= 0.0 {
+        if max_val > 1.0 {
+            1.0 // Start from 1 if max is reasonable
+        } else {
+            0.001 // Use a small positive value
+        }
+    } else {
+        min_val
+    };
+
+    let safe_max_val = if max_val <= 0.0 {
+        safe_min_val * 1000.0 // Ensure we have a reasonable range
+    } else {
+        max_val
+    };
+
+    let log_min = safe_min_val.log10().floor();
+    let log_max = safe_max_val.log10().ceil();
+    let mut ticks = Vec::new();
+
+    // Generate only major ticks (powers of 10)
+    for exp in (log_min as i32)..=(log_max as i32) {
+        let tick_value = 10.0_f32.powi(exp);
+        if tick_value >= safe_min_val && tick_value <= safe_max_val {
+            ticks.push(tick_value);
+        }
+    }
+
+    // Ensure we have at least some ticks
+    if ticks.is_empty() {
+        ticks.push(safe_min_val);
+        ticks.push(safe_max_val);
+    }
+
+    ticks
+}
 
 /// Generate minor tick values for logarithmic scale between major ticks
 fn generate_minor_log_ticks(major_ticks: &[f32]) -> Vec<f32> {
@@ -173,7 +264,7 @@ fn generate_minor_pi_ticks(major_ticks: &[f32]) -> Vec<f32> {
 }
 
 /// Format a value in terms of π for display
-fn format_pi_value(value: f32) -> String {
+pub fn format_pi_value(value: f32) -> String {
     const PI: f32 = std::f32::consts::PI;
     
     if value.abs() < 1e-6 {
@@ -196,8 +287,8 @@ fn format_pi_value(value: f32) -> String {
             }
             
             match (numerator, denom) {
-                (n, 1) if n == 1 => return "π".to_string(),
-                (n, 1) if n == -1 => return "-π".to_string(),
+                (1, 1) => return "π".to_string(),
+                (-1, 1) => return "-π".to_string(),
                 (n, 1) => return format!("{}π", n),
                 (n, d) if n.abs() == 1 && d == 2 => return if n > 0 { "π/2".to_string() } else { "-π/2".to_string() },
                 (n, d) if n.abs() == 1 => return if n > 0 { format!("π/{}", d) } else { format!("-π/{}", d) },
@@ -210,8 +301,118 @@ fn format_pi_value(value: f32) -> String {
     format!("{:.2}π", pi_ratio)
 }
 
+/// Returns the major ticks actually drawn for a scale, replacing them with π fractions on a Pi scale
+pub fn major_ticks(ticks: &[f32], scale: Scale) -> Vec<f32> {
+    if scale == Scale::Pi {
+        // Calculate range from provided ticks and generate Pi ticks
+        let min = ticks.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = ticks.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        generate_pi_ticks(min, max)
+    } else {
+        ticks.to_vec()
+    }
+}
+
+/// Returns the power of ten factored out of the tick labels for Scientific and Engineering scales
+pub fn scale_exponent(ticks: &[f32], scale: Scale) -> i32 {
+    // Log and Pi labels are formatted separately and None never scales
+    if scale != Scale::Scientific && scale != Scale::Engineering {
+        return 0;
+    }
+    let max_tick_abs = ticks.iter().fold(0.0f32, |acc, tick| acc.max(tick.abs()));
+    if max_tick_abs <= 0.0 {
+        return 0;
+    }
+
+    let mut current_power = 0;
+    if scale == Scale::Scientific {
+        // Scientific: normalize to 1.xxxx
+        if !(1.0..10.0).contains(&max_tick_abs) {
+            current_power = max_tick_abs.log10().floor() as i32;
+        }
+    } else {
+        // Engineering: normalize to xxx.xxxx with exponent multiple of 3
+        let exp = max_tick_abs.log10().floor() as i32;
+        current_power = (exp / 3) * 3;
+        // This ensures that the number of digits before decimal is between 1 and 3.
+        let scaled_val_check = max_tick_abs / 10.0_f32.powi(current_power);
+        if scaled_val_check >= 1000.0 {
+            current_power += 3;
+        } else if scaled_val_check < 1.0 && current_power == 0 {
+            // For very small numbers like 0.000123 -> 123 * 10^-6
+            let sub_exp = scaled_val_check.log10().floor() as i32;
+            current_power = ((sub_exp - 2) / 3) * 3; // Aim for xxx.yyy, so shift by 2 more than usual
+        }
+    }
+    current_power
+}
+
+/// Formats a major tick value as its label text.
+///
+/// Log labels use a `10^exponent` or `coefficient·10^exponent` form that is rendered with
+/// a superscript exponent; other labels are plain text.
+pub fn tick_label(value: f32, scale: Scale, scale_factor: f32) -> String {
+    match scale {
+        Scale::Log => {
+            // For log scale, always use scientific notation like "10³"
+            let abs_value = value.abs();
+            if abs_value == 0.0 {
+                return "0".to_string();
+            }
+            let log_value = abs_value.log10();
+            if (log_value.round() - log_value).abs() < 0.001 {
+                format!("10^{}", log_value.round() as i32)
+            } else {
+                // For intermediate values, use coefficient·10^exponent format
+                let exponent = log_value.floor() as i32;
+                let coefficient = value / 10.0_f32.powi(exponent);
+                if (coefficient - 1.0).abs() < 0.001 {
+                    format!("10^{}", exponent)
+                } else {
+                    format!("{:.1}·10^{}", coefficient, exponent)
+                }
+            }
+        }
+        // For Pi scale, format values in terms of π
+        Scale::Pi => format_pi_value(value),
+        _ => format!("{:.1}", value / scale_factor),
+    }
+}
+
+/// Measures a label produced by [`tick_label`], accounting for the superscript exponent
+pub fn tick_label_width(label: &str, font: &str, font_size: f32) -> f32 {
+    match label.split_once('^') {
+        // The exponent is shifted left by 0.2em when drawn
+        Some((base, exponent)) => {
+            text_width(base, font, font_size) + text_width(exponent, font, font_size)
+                - font_size * 0.2
+        }
+        None => text_width(label, font, font_size),
+    }
+}
+
+/// Dash pattern for a grid line style, empty for solid lines
+pub fn grid_dash_array(grid: Grid) -> &'static [f32] {
+    match grid {
+        Grid::Dotted => &[1.0, 2.0],
+        Grid::Dashed => &[4.0, 4.0],
+        Grid::Solid | Grid::None => &[],
+    }
+}
+
+/// Positions a label produced by [`tick_label`], raising the exponent of `10^exponent` forms
+fn tick_label_text(position: Point, label: &str) -> Text<'_> {
+    match label.split_once('^') {
+        Some((base, exponent)) => Text {
+            superscript: Some(exponent),
+            ..Text::new(position, base)
+        },
+        None => Text::new(position, label),
+    }
+}
+
 pub fn draw_ticks_and_grids<FX, FY>(
-    document: Document,
+    backend: &mut dyn Backend,
     axis: Axis,
     tick: Tick,
     grid: Grid,
@@ -229,36 +430,13 @@ pub fn draw_ticks_and_grids<FX, FY>(
     y_ticks: &[f32],
     map_x: FX,
     map_y: FY,
-) -> Document
-where
+) where
     FX: Fn(f32) -> f32,
     FY: Fn(f32) -> f32,
 {
-    let tick_label_color_svg = tick_config.label_color.to_hex_string();
-    let tick_line_color_svg = tick_config.line_color.to_hex_string();
-    let minor_tick_color_svg = tick_config.minor_tick_color.to_hex_string();
-    let grid_line_color_svg = grid_config.color.to_hex_string();
-    let minor_grid_color_svg = grid_config.minor_color.to_hex_string();
-    let mut document = document;
-
     // Override ticks with Pi-appropriate values when Pi scale is used
-    let actual_x_ticks = if x_scale == Scale::Pi {
-        // Calculate range from provided ticks and generate Pi ticks
-        let x_min = x_ticks.iter().copied().fold(f32::INFINITY, f32::min);
-        let x_max = x_ticks.iter().copied().fold(f32::NEG_INFINITY, f32::max);
-        generate_pi_ticks(x_min, x_max)
-    } else {
-        x_ticks.to_vec()
-    };
-
-    let actual_y_ticks = if y_scale == Scale::Pi {
-        // Calculate range from provided ticks and generate Pi ticks
-        let y_min = y_ticks.iter().copied().fold(f32::INFINITY, f32::min);
-        let y_max = y_ticks.iter().copied().fold(f32::NEG_INFINITY, f32::max);
-        generate_pi_ticks(y_min, y_max)
-    } else {
-        y_ticks.to_vec()
-    };
+    let actual_x_ticks = major_ticks(x_ticks, x_scale);
+    let actual_y_ticks = major_ticks(y_ticks, y_scale);
 
     // Generate minor ticks for all scale types when enabled
     let x_minor_ticks = match minor_grid {
@@ -283,641 +461,189 @@ where
         _ => Vec::new(),
     };
 
-    // Determine Y-axis scaling factor
-    let mut y_scale_factor = 1.0;
-    let mut y_scale_exponent = 0;
+    // Determine axis scaling factors
+    let y_scale_exponent = scale_exponent(&actual_y_ticks, y_scale);
+    let y_scale_factor = 10.0_f32.powi(y_scale_exponent);
+    let x_scale_exponent = scale_exponent(&actual_x_ticks, x_scale);
+    let x_scale_factor = 10.0_f32.powi(x_scale_exponent);
 
-    match y_scale {
-        Scale::None => {
-            // No scaling, factor remains 1.0, exponent 0
-        }
-        Scale::Log => {
-            // For logarithmic scale, we don't use a simple scaling factor
-            // The tick values should already be in log space if using log scale
-            // This will need more comprehensive implementation in the future
-        }
-        Scale::Pi => {
-            // For Pi scale, no scaling factor needed as formatting is handled separately
-        }
-        Scale::Scientific | Scale::Engineering => {
-            let mut max_tick_abs = 0.0;
-            for &tick_val in actual_y_ticks.iter() {
-                if tick_val.abs() > max_tick_abs {
-                    max_tick_abs = tick_val.abs();
-                }
-            }
-
-            if max_tick_abs > 0.0 {
-                let mut current_power = 0;
-                let temp_max = max_tick_abs;
-
-                if y_scale == Scale::Scientific {
-                    // Scientific: normalize to 1.xxxx
-                    if temp_max >= 10.0 || (temp_max < 1.0 && temp_max > 0.0) {
-                        current_power = temp_max.log10().floor() as i32;
-                    }
-                } else {
-                    // Engineering: normalize to xxx.xxxx with exponent multiple of 3
-                    let exp = temp_max.log10().floor() as i32;
-                    current_power = (exp / 3) * 3;
-                    // Adjust temp_max to reflect the engineering scaling for the following format call, if needed
-                    // This ensures that the number of digits before decimal is between 1 and 3.
-                    let scaled_val_check = temp_max / 10.0_f32.powi(current_power);
-                    if scaled_val_check >= 1000.0 {
-                        current_power += 3;
-                    } else if scaled_val_check < 1.0 && scaled_val_check > 0.0 {
-                        // this case should ideally not be hit if numbers are typical positives
-                        // but if max_tick_abs was < 1 initially, engineering might make it 0.xxx * 10^0
-                        // or for very small numbers like 0.000123 -> 123 * 10^-6
-                        // if current_power is 0 and val is < 1, we might want to shift to e.g. 123u * 10^-3
-                        if current_power == 0 && scaled_val_check < 1.0 {
-                            let sub_exp = scaled_val_check.log10().floor() as i32;
-                            current_power = ((sub_exp - 2) / 3) * 3; // Aim for xxx.yyy, so shift by 2 more than usual
-                        }
-                    }
-                }
-
-                if current_power != 0 {
-                    y_scale_factor = 10.0_f32.powi(current_power);
-                    y_scale_exponent = current_power;
-                }
-            }
-        }
-    }
-
-    // Determine X-axis scaling factor
-    let mut x_scale_factor = 1.0;
-    let mut x_scale_exponent = 0;
-
-    match x_scale {
-        Scale::None => {
-            // No scaling, factor remains 1.0, exponent 0
-        }
-        Scale::Log => {
-            // For logarithmic scale, we don't use a simple scaling factor
-            // The tick values should already be in log space if using log scale
-            // This will need more comprehensive implementation in the future
-        }
-        Scale::Pi => {
-            // For Pi scale, no scaling factor needed as formatting is handled separately
-        }
-        Scale::Scientific | Scale::Engineering => {
-            let mut max_tick_abs = 0.0;
-            for &tick_val in actual_x_ticks.iter() {
-                if tick_val.abs() > max_tick_abs {
-                    max_tick_abs = tick_val.abs();
-                }
-            }
-
-            if max_tick_abs > 0.0 {
-                let mut current_power = 0;
-                let temp_max = max_tick_abs;
-
-                if x_scale == Scale::Scientific {
-                    // Scientific: normalize to 1.xxxx
-                    if temp_max >= 10.0 || (temp_max < 1.0 && temp_max > 0.0) {
-                        current_power = temp_max.log10().floor() as i32;
-                    }
-                } else {
-                    // Engineering: normalize to xxx.xxxx with exponent multiple of 3
-                    let exp = temp_max.log10().floor() as i32;
-                    current_power = (exp / 3) * 3;
-                    let scaled_val_check = temp_max / 10.0_f32.powi(current_power);
-                    if scaled_val_check >= 1000.0 {
-                        current_power += 3;
-                    } else if scaled_val_check < 1.0 && scaled_val_check > 0.0 {
-                        if current_power == 0 && scaled_val_check < 1.0 {
-                            let sub_exp = scaled_val_check.log10().floor() as i32;
-                            current_power = ((sub_exp - 2) / 3) * 3;
-                        }
-                    }
-                }
-
-                if current_power != 0 {
-                    x_scale_factor = 10.0_f32.powi(current_power);
-                    x_scale_exponent = current_power;
-                }
-            }
-        }
-    }
+    // Minor grid lines share the dash pattern of the major grid
+    let grid_stroke = Stroke {
+        dash: grid_dash_array(grid),
+        ..Stroke::new(grid_config.color.clone(), grid_config.line_width)
+    };
+    let minor_grid_stroke = Stroke {
+        dash: grid_dash_array(grid),
+        ..Stroke::new(grid_config.minor_color.clone(), grid_config.minor_line_width)
+    };
+    let tick_stroke = Stroke::new(tick_config.line_color.clone(), 1.0);
+    let minor_tick_stroke = Stroke::new(tick_config.minor_tick_color.clone(), 0.5);
+    let label_style = TextStyle {
+        font,
+        size: tick_config.font_size,
+        color: tick_config.label_color.clone(),
+        bold: false,
+        anchor: TextAnchor::Middle,
+        baseline: TextBaseline::Hanging,
+    };
+    let tick_direction = if tick == Tick::Inward { -1.0 } else { 1.0 };
+    let plot_area_x_end = plot_area_x_start + plot_area_width;
+    let plot_area_y_end = plot_area_y_start + plot_area_height;
 
     for &tick_val in actual_x_ticks.iter() {
         let screen_x = map_x(tick_val);
-        let is_origin = (screen_x - plot_area_x_start).abs() < 0.1;
-        if screen_x >= plot_area_x_start - 0.1
-            && screen_x <= plot_area_x_start + plot_area_width + 0.1
-        {
-            match grid {
-                Grid::None => {}
-                Grid::Solid | Grid::Dashed | Grid::Dotted => {
-                    let mut skip_grid_line = false;
-                    if is_origin {
-                        skip_grid_line = true;
-                    }
-                    if axis == Axis::Box
-                        && (screen_x - (plot_area_x_start + plot_area_width)).abs() < 0.1
-                    {
-                        skip_grid_line = true;
-                    }
-                    if !skip_grid_line {
-                        let mut grid_line = SvgLine::new()
-                            .set("x1", screen_x)
-                            .set("y1", plot_area_y_start)
-                            .set("x2", screen_x)
-                            .set("y2", plot_area_y_start + plot_area_height)
-                            .set("stroke", grid_line_color_svg.clone())
-                            .set("stroke-width", grid_config.line_width);
-                        match grid {
-                            Grid::Dotted => {
-                                grid_line = grid_line.set("stroke-dasharray", "1 2");
-                            }
-                            Grid::Dashed => {
-                                grid_line = grid_line.set("stroke-dasharray", "4 4");
-                            }
-                            Grid::Solid | Grid::None => {}
-                        }
-                        document = document.add(grid_line);
-                    }
-                }
+        if screen_x >= plot_area_x_start - 0.1 && screen_x <= plot_area_x_end + 0.1 {
+            // Skip grid lines drawn over the axis lines
+            let on_axis = (screen_x - plot_area_x_start).abs() < 0.1
+                || (axis == Axis::Box && (screen_x - plot_area_x_end).abs() < 0.1);
+            if grid != Grid::None && !on_axis {
+                backend.set_role(Role::Grid);
+                backend.line(
+                    (screen_x, plot_area_y_start),
+                    (screen_x, plot_area_y_end),
+                    &grid_stroke,
+                );
             }
             if tick != Tick::None {
-                let tick_direction = if tick == Tick::Inward { -1.0 } else { 1.0 };
-                let tick_y_bottom = plot_area_y_start + plot_area_height;
-                let tick_y_top = plot_area_y_start;
                 let tick_label_offset = tick_config.font_size * 0.4 + 5.0;
-                match axis {
-                    Axis::BottomLeft | Axis::Box => {
-                        let tick_line_bottom = SvgLine::new()
-                            .set("x1", screen_x)
-                            .set("y1", tick_y_bottom)
-                            .set("x2", screen_x)
-                            .set("y2", tick_y_bottom + tick_config.length * tick_direction)
-                            .set("stroke", tick_line_color_svg.clone())
-                            .set("stroke-width", 1.0);
-                        document = document.add(tick_line_bottom);
-                        let tick_label_text_bottom = if x_scale == Scale::Log {
-                            // For log scale, always use scientific notation like "10³"
-                            let abs_value = tick_val.abs();
-                            if abs_value == 0.0 {
-                                "0".to_string()
-                            } else {
-                                let log_value = abs_value.log10();
-                                if (log_value.round() - log_value).abs() < 0.001 {
-                                    let exponent = log_value.round() as i32;
-                                    // Always use scientific notation: "10^exponent"
-                                    format!("10^{}", exponent)
-                                } else {
-                                    // For intermediate values, use coefficient·10^exponent format
-                                    let coefficient = tick_val / 10.0_f32.powi(log_value.floor() as i32);
-                                    let exponent = log_value.floor() as i32;
-                                    if (coefficient - 1.0).abs() < 0.001 {
-                                        format!("10^{}", exponent)
-                                    } else {
-                                        format!("{:.1}·10^{}", coefficient, exponent)
-                                    }
-                                }
-                            }
-                        } else if x_scale == Scale::Pi {
-                            // For Pi scale, format values in terms of π
-                            format_pi_value(tick_val)
-                        } else {
-                            format!("{:.1}", tick_val / x_scale_factor)
-                        };
-
-                        // Handle logarithmic labels with proper superscript formatting for x-axis
-                        if x_scale == Scale::Log && (tick_label_text_bottom.contains("10^") || tick_label_text_bottom.contains("·10^")) {
-                            // Handle both "10^exponent" and "coefficient·10^exponent" formats
-                            if let Some(cap) = tick_label_text_bottom.strip_prefix("10^") {
-                                // Simple "10^exponent" format
-                                let exponent = cap.parse::<i32>().unwrap_or(0);
-                                
-                                let base_text_node = SvgNodeText::new("10");
-                                let exponent_tspan = svg::node::element::TSpan::new()
-                                    .set("dy", "-0.4em") // Shift exponent upwards
-                                    .set("dx", "-0.2em") // Shift left to align with base
-                                    .add(SvgNodeText::new(exponent.to_string()));
-                                
-                                let tick_label_svg_bottom = Text::new()
-                                    .set("x", screen_x)
-                                    .set("y", tick_y_bottom + tick_label_offset)
-                                    .set("font-family", font)
-                                    .set("font-size", tick_config.font_size)
-                                    .set("fill", tick_label_color_svg.clone())
-                                    .set("text-anchor", "middle")
-                                    .set("dominant-baseline", "hanging")
-                                    .add(base_text_node)
-                                    .add(exponent_tspan);
-                                document = document.add(tick_label_svg_bottom);
-                            } else if let Some(pos) = tick_label_text_bottom.find("·10^") {
-                                // "coefficient·10^exponent" format
-                                let coefficient = &tick_label_text_bottom[..pos];
-                                let exponent_str = &tick_label_text_bottom[pos + 4..]; // Skip "·10^"
-                                let exponent = exponent_str.parse::<i32>().unwrap_or(0);
-                                
-                                let base_text_node = SvgNodeText::new(&format!("{}·10", coefficient));
-                                let exponent_tspan = svg::node::element::TSpan::new()
-                                    .set("dy", "-0.4em") // Shift exponent upwards
-                                    .set("dx", "-0.2em") // Shift left to align with base
-                                    .add(SvgNodeText::new(exponent.to_string()));
-                                
-                                let tick_label_svg_bottom = Text::new()
-                                    .set("x", screen_x)
-                                    .set("y", tick_y_bottom + tick_label_offset)
-                                    .set("font-family", font)
-                                    .set("font-size", tick_config.font_size)
-                                    .set("fill", tick_label_color_svg.clone())
-                                    .set("text-anchor", "middle")
-                                    .set("dominant-baseline", "hanging")
-                                    .add(base_text_node)
-                                    .add(exponent_tspan);
-                                document = document.add(tick_label_svg_bottom);
-                            } else {
-                                // Fallback to simple text
-                                let tick_label_svg_bottom = Text::new()
-                                    .set("x", screen_x)
-                                    .set("y", tick_y_bottom + tick_label_offset)
-                                    .set("font-family", font)
-                                    .set("font-size", tick_config.font_size)
-                                    .set("fill", tick_label_color_svg.clone())
-                                    .set("text-anchor", "middle")
-                                    .set("dominant-baseline", "hanging")
-                                    .add(SvgNodeText::new(tick_label_text_bottom));
-                                document = document.add(tick_label_svg_bottom);
-                            }
-                        } else if x_scale == Scale::Pi && tick_label_text_bottom.contains("π") {
-                            // Handle Pi scale labels with proper Unicode π symbol
-                            let tick_label_svg_bottom = Text::new()
-                                .set("x", screen_x)
-                                .set("y", tick_y_bottom + tick_label_offset)
-                                .set("font-family", font)
-                                .set("font-size", tick_config.font_size)
-                                .set("fill", tick_label_color_svg.clone())
-                                .set("text-anchor", "middle")
-                                .set("dominant-baseline", "hanging")
-                                .add(SvgNodeText::new(tick_label_text_bottom));
-                            document = document.add(tick_label_svg_bottom);
-                        } else {
-                            // Normal text formatting for non-logarithmic or simple logarithmic labels
-                            let tick_label_svg_bottom = Text::new()
-                                .set("x", screen_x)
-                                .set("y", tick_y_bottom + tick_label_offset)
-                                .set("font-family", font)
-                                .set("font-size", tick_config.font_size)
-                                .set("fill", tick_label_color_svg.clone())
-                                .set("text-anchor", "middle")
-                                .set("dominant-baseline", "hanging")
-                                .add(SvgNodeText::new(tick_label_text_bottom));
-                            document = document.add(tick_label_svg_bottom);
-                        }
-                    }
-                }
+                backend.set_role(Role::Tick);
+                backend.line(
+                    (screen_x, plot_area_y_end),
+                    (screen_x, plot_area_y_end + tick_config.length * tick_direction),
+                    &tick_stroke,
+                );
+                let label = tick_label(tick_val, x_scale, x_scale_factor);
+                let position = (screen_x, plot_area_y_end + tick_label_offset);
+                backend.set_role(Role::TickLabel);
+                backend.text(&tick_label_text(position, &label), &label_style);
                 if axis == Axis::Box {
-                    let tick_line_top = SvgLine::new()
-                        .set("x1", screen_x)
-                        .set("y1", tick_y_top)
-                        .set("x2", screen_x)
-                        .set("y2", tick_y_top - tick_config.length * tick_direction)
-                        .set("stroke", tick_line_color_svg.clone())
-                        .set("stroke-width", 1.0);
-                    document = document.add(tick_line_top);
+                    backend.set_role(Role::Tick);
+                    backend.line(
+                        (screen_x, plot_area_y_start),
+                        (screen_x, plot_area_y_start - tick_config.length * tick_direction),
+                        &tick_stroke,
+                    );
                 }
             }
         }
     }
+
     // Draw Y-axis scale factor label if needed
     if y_scale_factor != 1.0 && y_scale != Scale::None && y_scale != Scale::Log && y_scale != Scale::Pi {
-        let exponent_str = y_scale_exponent;
-        let base_text_node = SvgNodeText::new("·10");
-
-        let exponent_tspan = svg::node::element::TSpan::new()
-            .set("dy", "-0.4em") // Shift exponent upwards. Adjust value if needed.
-            .set("dx", "-0.2em") // Shift left to align with base
-            .add(SvgNodeText::new(exponent_str.to_string()));
-
-        let scale_label_svg = Text::new()
-            .set("x", plot_area_x_start + tick_config.text_padding)
-            .set("y", plot_area_y_start - tick_config.text_padding)
-            .set("font-family", font)
-            .set("font-size", tick_config.font_size)
-            .set("fill", tick_label_color_svg.clone())
-            .set("text-anchor", "start")
-            .set("dominant-baseline", "text-after-edge")
-            .add(base_text_node)
-            .add(exponent_tspan);
-        document = document.add(scale_label_svg);
+        let exponent = y_scale_exponent.to_string();
+        let text = Text {
+            superscript: Some(&exponent),
+            ..Text::new(
+                (
+                    plot_area_x_start + tick_config.text_padding,
+                    plot_area_y_start - tick_config.text_padding,
+                ),
+                "·10",
+            )
+        };
+        let style = TextStyle {
+            anchor: TextAnchor::Start,
+            baseline: TextBaseline::Bottom,
+            ..label_style.clone()
+        };
+        backend.set_role(Role::TickLabel);
+        backend.text(&text, &style);
     }
 
     // Draw X-axis scale factor label if needed
     if x_scale_factor != 1.0 && x_scale != Scale::None && x_scale != Scale::Log && x_scale != Scale::Pi {
-        let exponent_str = x_scale_exponent;
-        let base_text_node = SvgNodeText::new("·10");
-
-        let exponent_tspan = svg::node::element::TSpan::new()
-            .set("dy", "-0.4em") // Shift exponent upwards
-            .set("dx", "-0.2em") // Shift left to align with base
-            .add(SvgNodeText::new(exponent_str.to_string()));
-
-        let scale_label_svg = Text::new()
-            .set("x", plot_area_x_start + plot_area_width - tick_config.text_padding)
-            .set("y", plot_area_y_start + plot_area_height + tick_config.font_size + tick_config.text_padding * 2.0)
-            .set("font-family", font)
-            .set("font-size", tick_config.font_size)
-            .set("fill", tick_label_color_svg.clone())
-            .set("text-anchor", "end")
-            .set("dominant-baseline", "text-before-edge")
-            .add(base_text_node)
-            .add(exponent_tspan);
-        document = document.add(scale_label_svg);
+        let exponent = x_scale_exponent.to_string();
+        let text = Text {
+            superscript: Some(&exponent),
+            ..Text::new(
+                (
+                    plot_area_x_end - tick_config.text_padding,
+                    plot_area_y_end + tick_config.font_size + tick_config.text_padding * 2.0,
+                ),
+                "·10",
+            )
+        };
+        let style = TextStyle {
+            anchor: TextAnchor::End,
+            baseline: TextBaseline::Top,
+            ..label_style.clone()
+        };
+        backend.set_role(Role::TickLabel);
+        backend.text(&text, &style);
     }
 
     // Draw X-axis minor ticks and grids
     for &minor_tick_val in x_minor_ticks.iter() {
         let screen_x = map_x(minor_tick_val);
-        if screen_x >= plot_area_x_start - 0.1
-            && screen_x <= plot_area_x_start + plot_area_width + 0.1
-        {
-            // Draw minor grid lines
-            if matches!(minor_grid, MinorGrid::XAxis | MinorGrid::Both) {
-                match grid {
-                    Grid::None => {}
-                    Grid::Solid | Grid::Dashed | Grid::Dotted => {
-                        let mut minor_grid_line = SvgLine::new()
-                            .set("x1", screen_x)
-                            .set("y1", plot_area_y_start)
-                            .set("x2", screen_x)
-                            .set("y2", plot_area_y_start + plot_area_height)
-                            .set("stroke", minor_grid_color_svg.clone())
-                            .set("stroke-width", grid_config.minor_line_width);
-                        
-                        // Apply the same dash pattern as the major grid
-                        match grid {
-                            Grid::Dotted => {
-                                minor_grid_line = minor_grid_line.set("stroke-dasharray", "1 2");
-                            }
-                            Grid::Dashed => {
-                                minor_grid_line = minor_grid_line.set("stroke-dasharray", "4 4");
-                            }
-                            Grid::Solid | Grid::None => {}
-                        }
-                        
-                        document = document.add(minor_grid_line);
-                    }
-                }
+        if screen_x >= plot_area_x_start - 0.1 && screen_x <= plot_area_x_end + 0.1 {
+            if grid != Grid::None {
+                backend.set_role(Role::Grid);
+                backend.line(
+                    (screen_x, plot_area_y_start),
+                    (screen_x, plot_area_y_end),
+                    &minor_grid_stroke,
+                );
             }
-
-            // Draw minor tick marks
             if tick != Tick::None {
-                let tick_direction = if tick == Tick::Inward { -1.0 } else { 1.0 };
-                let tick_y_bottom = plot_area_y_start + plot_area_height;
-                match axis {
-                    Axis::BottomLeft | Axis::Box => {
-                        let minor_tick_line_bottom = SvgLine::new()
-                            .set("x1", screen_x)
-                            .set("y1", tick_y_bottom)
-                            .set("x2", screen_x)
-                            .set("y2", tick_y_bottom + tick_config.minor_tick_length * tick_direction)
-                            .set("stroke", minor_tick_color_svg.clone())
-                            .set("stroke-width", 0.5);
-                        document = document.add(minor_tick_line_bottom);
-                    }
-                }
+                backend.set_role(Role::Tick);
+                backend.line(
+                    (screen_x, plot_area_y_end),
+                    (screen_x, plot_area_y_end + tick_config.minor_tick_length * tick_direction),
+                    &minor_tick_stroke,
+                );
                 if axis == Axis::Box {
-                    let tick_y_top = plot_area_y_start;
-                    let minor_tick_line_top = SvgLine::new()
-                        .set("x1", screen_x)
-                        .set("y1", tick_y_top)
-                        .set("x2", screen_x)
-                        .set("y2", tick_y_top - tick_config.minor_tick_length * tick_direction)
-                        .set("stroke", minor_tick_color_svg.clone())
-                        .set("stroke-width", 0.5);
-                    document = document.add(minor_tick_line_top);
+                    backend.set_role(Role::Tick);
+                    backend.line(
+                        (screen_x, plot_area_y_start),
+                        (screen_x, plot_area_y_start - tick_config.minor_tick_length * tick_direction),
+                        &minor_tick_stroke,
+                    );
                 }
             }
         }
     }
 
+    let y_label_style = TextStyle {
+        anchor: TextAnchor::End,
+        baseline: TextBaseline::Middle,
+        ..label_style
+    };
     for &tick_val in actual_y_ticks.iter() {
         let screen_y = map_y(tick_val);
-        if screen_y >= plot_area_y_start - 0.1
-            && screen_y <= plot_area_y_start + plot_area_height + 0.1
-        {
-            match grid {
-                Grid::None => {}
-                Grid::Solid | Grid::Dashed | Grid::Dotted => {
-                    let mut skip_grid_line = false;
-                    if (screen_y - (plot_area_y_start + plot_area_height)).abs() < 0.1 {
-                        skip_grid_line = true;
-                    }
-                    if axis == Axis::Box && (screen_y - plot_area_y_start).abs() < 0.1 {
-                        skip_grid_line = true;
-                    }
-                    if !skip_grid_line {
-                        let mut grid_line = SvgLine::new()
-                            .set("x1", plot_area_x_start)
-                            .set("y1", screen_y)
-                            .set("x2", plot_area_x_start + plot_area_width)
-                            .set("y2", screen_y)
-                            .set("stroke", grid_line_color_svg.clone())
-                            .set("stroke-width", grid_config.line_width);
-                        match grid {
-                            Grid::Dotted => {
-                                grid_line = grid_line.set("stroke-dasharray", "1 2");
-                            }
-                            Grid::Dashed => {
-                                grid_line = grid_line.set("stroke-dasharray", "4 4");
-                            }
-                            Grid::Solid | Grid::None => {}
-                        }
-                        document = document.add(grid_line);
-                    }
-                }
+        if screen_y >= plot_area_y_start - 0.1 && screen_y <= plot_area_y_end + 0.1 {
+            // Skip grid lines drawn over the axis lines
+            let on_axis = (screen_y - plot_area_y_end).abs() < 0.1
+                || (axis == Axis::Box && (screen_y - plot_area_y_start).abs() < 0.1);
+            if grid != Grid::None && !on_axis {
+                backend.set_role(Role::Grid);
+                backend.line(
+                    (plot_area_x_start, screen_y),
+                    (plot_area_x_end, screen_y),
+                    &grid_stroke,
+                );
             }
             if tick != Tick::None {
-                let tick_direction = if tick == Tick::Inward { -1.0 } else { 1.0 };
-                let tick_x_left = plot_area_x_start;
-                let tick_x_right = plot_area_x_start + plot_area_width;
-                match axis {
-                    Axis::BottomLeft | Axis::Box => {
-                        let tick_line_left = SvgLine::new()
-                            .set("x1", tick_x_left)
-                            .set("y1", screen_y)
-                            .set("x2", tick_x_left - tick_config.length * tick_direction)
-                            .set("y2", screen_y)
-                            .set("stroke", tick_line_color_svg.clone())
-                            .set("stroke-width", 1.0);
-                        document = document.add(tick_line_left);
-                    }
-                }
+                backend.set_role(Role::Tick);
+                backend.line(
+                    (plot_area_x_start, screen_y),
+                    (plot_area_x_start - tick_config.length * tick_direction, screen_y),
+                    &tick_stroke,
+                );
                 if axis == Axis::Box {
-                    let tick_line_right = SvgLine::new()
-                        .set("x1", tick_x_right)
-                        .set("y1", screen_y)
-                        .set("x2", tick_x_right + tick_config.length * tick_direction)
-                        .set("y2", screen_y)
-                        .set("stroke", tick_line_color_svg.clone())
-                        .set("stroke-width", 1.0);
-                    document = document.add(tick_line_right);
-                }
-                let display_val = tick_val / y_scale_factor;
-                let tick_label_text = if y_scale == Scale::Log {
-                    // For log scale, always use scientific notation like "10³"
-                    let abs_value = tick_val.abs();
-                    if abs_value == 0.0 {
-                        "0".to_string()
-                    } else {
-                        let log_value = abs_value.log10();
-                        if (log_value.round() - log_value).abs() < 0.001 {
-                            let exponent = log_value.round() as i32;
-                            // Always use scientific notation: "10^exponent"
-                            format!("10^{}", exponent)
-                        } else {
-                            // For intermediate values, use coefficient·10^exponent format
-                            let coefficient = tick_val / 10.0_f32.powi(log_value.floor() as i32);
-                            let exponent = log_value.floor() as i32;
-                            if (coefficient - 1.0).abs() < 0.001 {
-                                format!("10^{}", exponent)
-                            } else {
-                                format!("{:.1}·10^{}", coefficient, exponent)
-                            }
-                        }
-                    }
-                } else if y_scale == Scale::Pi {
-                    // For Pi scale, format values in terms of π
-                    format_pi_value(tick_val)
-                } else {
-                    format!("{:.1}", display_val)
-                };
-
-                // Handle logarithmic labels with proper superscript formatting
-                if y_scale == Scale::Log && (tick_label_text.contains("10^") || tick_label_text.contains("·10^")) {
-                    // Handle both "10^exponent" and "coefficient·10^exponent" formats
-                    if let Some(cap) = tick_label_text.strip_prefix("10^") {
-                        // Simple "10^exponent" format
-                        let exponent = cap.parse::<i32>().unwrap_or(0);
-                        
-                        let base_text_node = SvgNodeText::new("10");
-                        let exponent_tspan = svg::node::element::TSpan::new()
-                            .set("dy", "-0.4em") // Shift exponent upwards
-                            .set("dx", "-0.2em") // Shift left to align with base
-                            .add(SvgNodeText::new(exponent.to_string()));
-                        
-                        let tick_label_svg = Text::new()
-                            .set(
-                                "x",
-                                tick_x_left
-                                    - tick_config.text_padding
-                                    - (if tick_direction > 0.0 {
-                                        tick_config.length
-                                    } else {
-                                        tick_config.length
-                                    }),
-                            )
-                            .set("y", screen_y)
-                            .set("font-family", font)
-                            .set("font-size", tick_config.font_size)
-                            .set("fill", tick_label_color_svg.clone())
-                            .set("text-anchor", "end")
-                            .set("dominant-baseline", "middle")
-                            .add(base_text_node)
-                            .add(exponent_tspan);
-                        document = document.add(tick_label_svg);
-                    } else if let Some(pos) = tick_label_text.find("·10^") {
-                        // "coefficient·10^exponent" format
-                        let coefficient = &tick_label_text[..pos];
-                        let exponent_str = &tick_label_text[pos + 4..]; // Skip "·10^"
-                        let exponent = exponent_str.parse::<i32>().unwrap_or(0);
-                        
-                        let base_text_node = SvgNodeText::new(&format!("{}·10", coefficient));
-                        let exponent_tspan = svg::node::element::TSpan::new()
-                            .set("dy", "-0.4em") // Shift exponent upwards
-                            .set("dx", "-0.2em") // Shift left to align with base
-                            .add(SvgNodeText::new(exponent.to_string()));
-                        
-                        let tick_label_svg = Text::new()
-                            .set(
-                                "x",
-                                tick_x_left
-                                    - tick_config.text_padding
-                                    - (if tick_direction > 0.0 {
-                                        tick_config.length
-                                    } else {
-                                        tick_config.length
-                                    }),
-                            )
-                            .set("y", screen_y)
-                            .set("font-family", font)
-                            .set("font-size", tick_config.font_size)
-                            .set("fill", tick_label_color_svg.clone())
-                            .set("text-anchor", "end")
-                            .set("dominant-baseline", "middle")
-                            .add(base_text_node)
-                            .add(exponent_tspan);
-                        document = document.add(tick_label_svg);
-                    } else {
-                        // Fallback to simple text
-                        let tick_label_svg = Text::new()
-                            .set(
-                                "x",
-                                tick_x_left
-                                    - tick_config.text_padding
-                                    - (if tick_direction > 0.0 {
-                                        tick_config.length
-                                    } else {
-                                        tick_config.length
-                                    }),
-                            )
-                            .set("y", screen_y)
-                            .set("font-family", font)
-                            .set("font-size", tick_config.font_size)
-                            .set("fill", tick_label_color_svg.clone())
-                            .set("text-anchor", "end")
-                            .set("dominant-baseline", "middle")
-                            .add(SvgNodeText::new(tick_label_text));
-                        document = document.add(tick_label_svg);
-                    }
-                } else if y_scale == Scale::Pi && tick_label_text.contains("π") {
-                    // Handle Pi scale labels with proper Unicode π symbol
-                    let tick_label_svg = Text::new()
-                        .set(
-                            "x",
-                            tick_x_left
-                                - tick_config.text_padding
-                                - (if tick_direction > 0.0 {
-                                    tick_config.length
-                                } else {
-                                    tick_config.length
-                                }),
-                        )
-                        .set("y", screen_y)
-                        .set("font-family", font)
-                        .set("font-size", tick_config.font_size)
-                        .set("fill", tick_label_color_svg.clone())
-                        .set("text-anchor", "end")
-                        .set("dominant-baseline", "middle")
-                        .add(SvgNodeText::new(tick_label_text));
-                    document = document.add(tick_label_svg);
-                } else {
-                    // Normal text formatting for non-logarithmic or simple logarithmic labels
-                    let tick_label_svg = Text::new()
-                        .set(
-                            "x",
-                            tick_x_left
-                                - tick_config.text_padding
-                                - (if tick_direction > 0.0 {
-                                    tick_config.length
-                                } else {
-                                    tick_config.length
-                                }),
-                        )
-                        .set("y", screen_y)
-                        .set("font-family", font)
-                        .set("font-size", tick_config.font_size)
-                        .set("fill", tick_label_color_svg.clone())
-                        .set("text-anchor", "end")
-                        .set("dominant-baseline", "middle")
-                        .add(SvgNodeText::new(tick_label_text));
-                    document = document.add(tick_label_svg);
+                    backend.set_role(Role::Tick);
+                    backend.line(
+                        (plot_area_x_end, screen_y),
+                        (plot_area_x_end + tick_config.length * tick_direction, screen_y),
+                        &tick_stroke,
+                    );
                 }
+                let label = tick_label(tick_val, y_scale, y_scale_factor);
+                let position = (
+                    plot_area_x_start - tick_config.text_padding - tick_config.length,
+                    screen_y,
+                );
+                backend.set_role(Role::TickLabel);
+                backend.text(&tick_label_text(position, &label), &y_label_style);
             }
         }
     }
@@ -925,68 +651,31 @@ where
     // Draw Y-axis minor ticks and grids
     for &minor_tick_val in y_minor_ticks.iter() {
         let screen_y = map_y(minor_tick_val);
-        if screen_y >= plot_area_y_start - 0.1
-            && screen_y <= plot_area_y_start + plot_area_height + 0.1
-        {
-            // Draw minor grid lines
-            if matches!(minor_grid, MinorGrid::YAxis | MinorGrid::Both) {
-                match grid {
-                    Grid::None => {}
-                    Grid::Solid | Grid::Dashed | Grid::Dotted => {
-                        let mut minor_grid_line = SvgLine::new()
-                            .set("x1", plot_area_x_start)
-                            .set("y1", screen_y)
-                            .set("x2", plot_area_x_start + plot_area_width)
-                            .set("y2", screen_y)
-                            .set("stroke", minor_grid_color_svg.clone())
-                            .set("stroke-width", grid_config.minor_line_width);
-                        
-                        // Apply the same dash pattern as the major grid
-                        match grid {
-                            Grid::Dotted => {
-                                minor_grid_line = minor_grid_line.set("stroke-dasharray", "1 2");
-                            }
-                            Grid::Dashed => {
-                                minor_grid_line = minor_grid_line.set("stroke-dasharray", "4 4");
-                            }
-                            Grid::Solid | Grid::None => {}
-                        }
-                        
-                        document = document.add(minor_grid_line);
-                    }
-                }
+        if screen_y >= plot_area_y_start - 0.1 && screen_y <= plot_area_y_end + 0.1 {
+            if grid != Grid::None {
+                backend.set_role(Role::Grid);
+                backend.line(
+                    (plot_area_x_start, screen_y),
+                    (plot_area_x_end, screen_y),
+                    &minor_grid_stroke,
+                );
             }
-
-            // Draw minor tick marks
             if tick != Tick::None {
-                let tick_direction = if tick == Tick::Inward { -1.0 } else { 1.0 };
-                let tick_x_left = plot_area_x_start;
-                let tick_x_right = plot_area_x_start + plot_area_width;
-                match axis {
-                    Axis::BottomLeft | Axis::Box => {
-                        let minor_tick_line_left = SvgLine::new()
-                            .set("x1", tick_x_left)
-                            .set("y1", screen_y)
-                            .set("x2", tick_x_left - tick_config.minor_tick_length * tick_direction)
-                            .set("y2", screen_y)
-                            .set("stroke", minor_tick_color_svg.clone())
-                            .set("stroke-width", 0.5);
-                        document = document.add(minor_tick_line_left);
-                    }
-                }
+                backend.set_role(Role::Tick);
+                backend.line(
+                    (plot_area_x_start, screen_y),
+                    (plot_area_x_start - tick_config.minor_tick_length * tick_direction, screen_y),
+                    &minor_tick_stroke,
+                );
                 if axis == Axis::Box {
-                    let minor_tick_line_right = SvgLine::new()
-                        .set("x1", tick_x_right)
-                        .set("y1", screen_y)
-                        .set("x2", tick_x_right + tick_config.minor_tick_length * tick_direction)
-                        .set("y2", screen_y)
-                        .set("stroke", minor_tick_color_svg.clone())
-                        .set("stroke-width", 0.5);
-                    document = document.add(minor_tick_line_right);
+                    backend.set_role(Role::Tick);
+                    backend.line(
+                        (plot_area_x_end, screen_y),
+                        (plot_area_x_end + tick_config.minor_tick_length * tick_direction, screen_y),
+                        &minor_tick_stroke,
+                    );
                 }
             }
         }
     }
-
-    document
 }
@@ -0,0 +1,261 @@
+use crate::{
+    backend::{Backend, Path, Role, ShapeStyle, Stroke},
+    color::Color,
+    traits::PlotValue,
+    elements::{ArrowHead, ArrowScale, FieldMode},
+    vector_field::VectorField,
+};
+
+/// Draws an arrow from `start` to `end` in screen coordinates
+fn draw_arrow(
+    backend: &mut dyn Backend,
+    field_line_width: f32,
+    head: ArrowHead,
+    head_length: f32,
+    head_width: f32,
+    start: (f32, f32),
+    end: (f32, f32),
+    color: &Color,
+) {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length = dx.hypot(dy);
+    if length < 1e-3 {
+        return;
+    }
+    let (ux, uy) = (dx / length, dy / length);
+    // Shrink the head proportionally for arrows shorter than the head itself
+    let shrink = (length / head_length.max(1e-3)).min(1.0);
+    let (head_length, head_width) = (head_length * shrink, head_width * shrink);
+    let back = (end.0 - ux * head_length, end.1 - uy * head_length);
+    let left = (back.0 - uy * head_width / 2.0, back.1 + ux * head_width / 2.0);
+    let right = (back.0 + uy * head_width / 2.0, back.1 - ux * head_width / 2.0);
+
+    let shaft_end = if head == ArrowHead::Filled { back } else { end };
+    let mut shaft = Path::new().move_to(start).line_to(shaft_end);
+    if head == ArrowHead::Open {
+        shaft = shaft.move_to(left).line_to(end).line_to(right);
+    }
+    let shaft_stroke = Stroke::new(color.clone(), field_line_width);
+    backend.path(&shaft, &ShapeStyle::stroked(shaft_stroke));
+
+    if head == ArrowHead::Filled {
+        let head_data = Path::new().move_to(end).line_to(left).line_to(right).close();
+        backend.path(&head_data, &ShapeStyle::filled(color.clone()));
+    }
+}
+
+/// Smallest distance between two points further apart than half a pixel, or infinity.
+///
+/// Points are sorted by x and each is only compared with those less than the best distance
+/// so far to its right, which for grid-like quiver data avoids comparing every pair.
+fn nearest_spacing(points: &[(f32, f32)]) -> f32 {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    let mut spacing = f32::INFINITY;
+    for (i, a) in sorted.iter().enumerate() {
+        for b in &sorted[i + 1..] {
+            if b.0 - a.0 >= spacing {
+                break;
+            }
+            let d = (a.0 - b.0).hypot(a.1 - b.1);
+            if d > 0.5 {
+                spacing = spacing.min(d);
+            }
+        }
+    }
+    spacing
+}
+
+/// Draws quiver arrows or streamlines for each field.
+///
+/// `map_x` and `map_y` take data coordinates to screen coordinates, and `pixels_per_unit`
+/// gives the screen length of one data unit along x and y at a data point, which orients
+/// the arrows without mapping points nudged along them.
+pub fn draw_vector_fields<T, Fx, Fy, Fs>(
+    backend: &mut dyn Backend,
+    fields: &[VectorField<T>],
+    map_x: &Fx,
+    map_y: &Fy,
+    pixels_per_unit: &Fs,
+) where
+    T: PlotValue,
+    Fx: Fn(f32) -> f32,
+    Fy: Fn(f32) -> f32,
+    Fs: Fn(f32, f32) -> (f32, f32),
+{
+    for (index, field) in fields.iter().enumerate() {
+        backend.set_role(Role::VectorField(index));
+        let magnitude_range = field.magnitude_range();
+        match field.mode {
+            FieldMode::Quiver => {
+                let screen_points: Vec<(f32, f32)> = field
+                    .data
+                    .iter()
+                    .map(|&(x, y, _, _)| (map_x(x.to_f32()), map_y(y.to_f32())))
+                    .collect();
+
+                // Reference arrow length is the distance to the nearest neighboring point
+                let spacing = nearest_spacing(&screen_points);
+                let reference_length = if spacing.is_finite() { spacing * 0.9 } else { 20.0 };
+                let max_magnitude = magnitude_range.1;
+
+                for (&(x, y, u, v), &start) in field.data.iter().zip(screen_points.iter()) {
+                    let (x, y, u, v) = (x.to_f32(), y.to_f32(), u.to_f32(), v.to_f32());
+                    let magnitude = u.hypot(v);
+                    if magnitude <= 0.0 {
+                        continue;
+                    }
+                    let end = match field.scale {
+                        ArrowScale::Factor(factor) => {
+                            (map_x(x + u * factor), map_y(y + v * factor))
+                        }
+                        ArrowScale::Auto | ArrowScale::Uniform => {
+                            // Screen y grows downwards, against the data y axis
+                            let (x_density, y_density) = pixels_per_unit(x, y);
+                            let (dx, dy) = (u * x_density, -v * y_density);
+                            let direction_length = dx.hypot(dy);
+                            if !(direction_length > 0.0 && direction_length.is_finite()) {
+                                continue;
+                            }
+                            let length = if field.scale == ArrowScale::Uniform {
+                                reference_length
+                            } else {
+                                reference_length * magnitude / max_magnitude
+                            };
+                            (
+                                start.0 + dx / direction_length * length,
+                                start.1 + dy / direction_length * length,
+                            )
+                        }
+                    };
+                    let color = field.color_for(magnitude, magnitude_range);
+                    draw_arrow(
+                        backend,
+                        field.line_width,
+                        field.head,
+                        field.head_length,
+                        field.head_width,
+                        start,
+                        end,
+                        &color,
+                    );
+                }
+            }
+            FieldMode::Streamlines => {
+                for line in field.streamlines() {
+                    let screen: Vec<(f32, f32)> = line
+                        .iter()
+                        .map(|&(x, y, _)| (map_x(x), map_y(y)))
+                        .collect();
+                    let mean_speed =
+                        line.iter().map(|p| p.2).sum::<f32>() / line.len() as f32;
+                    let color = field.color_for(mean_speed, magnitude_range);
+
+                    let mut line_data = Path::new().move_to(screen[0]);
+                    for &point in screen.iter().skip(1) {
+                        line_data = line_data.line_to(point);
+                    }
+                    let line_stroke = Stroke::new(color.clone(), field.line_width);
+                    backend.path(&line_data, &ShapeStyle::stroked(line_stroke));
+
+                    // Mark the direction of flow halfway along the line
+                    let mid = screen.len() / 2;
+                    let (dx, dy) = (screen[mid].0 - screen[mid - 1].0, screen[mid].1 - screen[mid - 1].1);
+                    let segment_length = dx.hypot(dy);
+                    if field.head != ArrowHead::None && segment_length > 0.0 {
+                        let start = (
+                            screen[mid].0 - dx / segment_length * field.head_length,
+                            screen[mid].1 - dy / segment_length * field.head_length,
+                        );
+                        draw_arrow(
+                            backend,
+                            field.line_width,
+                            field.head,
+                            field.head_length,
+                            field.head_width,
+                            start,
+                            screen[mid],
+                            &color,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{Clip, Point, Rect, Segment, Text, TextStyle};
+
+    /// Keeps the start and end of every stroked two-point path, which are arrow shafts
+    /// when arrows have no head
+    #[derive(Default)]
+    struct Shafts(Vec<(Point, Point)>);
+
+    impl Backend for Shafts {
+        fn begin(&mut self, _width: f32, _height: f32) {}
+        fn line(&mut self, _from: Point, _to: Point, _stroke: &Stroke) {}
+        fn path(&mut self, path: &Path, style: &ShapeStyle) {
+            if let (Some(_), [Segment::MoveTo(start), Segment::LineTo(end)]) =
+                (&style.stroke, path.segments.as_slice())
+            {
+                self.0.push((*start, *end));
+            }
+        }
+        fn rect(&mut self, _rect: &Rect, _style: &ShapeStyle) {}
+        fn circle(&mut self, _center: Point, _radius: f32, _style: &ShapeStyle) {}
+        fn text(&mut self, _text: &Text, _style: &TextStyle) {}
+        fn begin_group(&mut self) {}
+        fn end_group(&mut self) {}
+        fn begin_clip(&mut self, _id: &str, _clip: &Clip) {}
+        fn end_clip(&mut self) {}
+    }
+
+    /// Arrow lengths on screen at 100 pixels per data unit, with y pointing up
+    fn arrows(scale: ArrowScale, data: Vec<(f32, f32, f32, f32)>) -> Vec<(Point, Point)> {
+        let field = VectorField::builder().data(data).scale(scale).head(ArrowHead::None).build();
+        let mut backend = Shafts::default();
+        let map_x = |x: f32| 50.0 + x * 100.0;
+        let map_y = |y: f32| 250.0 - y * 100.0;
+        draw_vector_fields(&mut backend, &[field], &map_x, &map_y, &|_, _| (100.0, 100.0));
+        backend.0
+    }
+
+    fn length((start, end): (Point, Point)) -> f32 {
+        (end.0 - start.0).hypot(end.1 - start.1)
+    }
+
+    #[test]
+    fn auto_scale_fits_the_longest_arrow_to_the_spacing() {
+        let data = vec![(0.0, 0.0, 2.0, 0.0), (1.0, 0.0, 0.0, 1.0), (2.0, 0.0, 0.0, 0.0)];
+        let shafts = arrows(ArrowScale::Auto, data);
+        // The zero vector draws nothing
+        assert_eq!(shafts.len(), 2);
+        assert_eq!(shafts[0].0, (50.0, 250.0));
+        assert!((length(shafts[0]) - 90.0).abs() < 1e-3);
+        assert!((length(shafts[1]) - 45.0).abs() < 1e-3);
+        // Positive v points up the screen
+        assert!(shafts[1].1.1 < shafts[1].0.1);
+    }
+
+    #[test]
+    fn uniform_and_factor_scales() {
+        let data = vec![(0.0, 0.0, 1.0, 0.0), (1.0, 0.0, 0.5, 0.0), (2.0, 0.0, 0.25, 0.0)];
+        let uniform = arrows(ArrowScale::Uniform, data.clone());
+        assert!(uniform.iter().all(|&shaft| (length(shaft) - 90.0).abs() < 1e-3));
+
+        // Half of each vector in data units, at 100 pixels per unit
+        let factor = arrows(ArrowScale::Factor(0.5), data);
+        let lengths: Vec<f32> = factor.into_iter().map(length).collect();
+        assert_eq!(lengths, [50.0, 25.0, 12.5]);
+    }
+
+    #[test]
+    fn nearest_spacing_ignores_coincident_points() {
+        let points = [(0.0, 0.0), (0.2, 0.0), (-30.0, 40.0), (100.0, 0.0)];
+        assert_eq!(nearest_spacing(&points), 50.0);
+        assert_eq!(nearest_spacing(&[(1.0, 1.0)]), f32::INFINITY);
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AngleUnit {
+    /// Labels such as "90°"
+    Degrees,
+    /// Labels as fractions of π, such as "π/2"
+    Radians,
+}
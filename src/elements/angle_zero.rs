@@ -0,0 +1,8 @@
+/// Screen direction of the zero angle on a polar plot
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AngleZero {
+    East,
+    North,
+    West,
+    South,
+}
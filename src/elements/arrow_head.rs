@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArrowHead {
+    /// Filled triangular head
+    Filled,
+    /// Two open strokes
+    Open,
+    None,
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArrowScale {
+    /// Longest arrow spans the spacing between neighboring points
+    Auto,
+    /// Every arrow has the same length, showing direction only
+    Uniform,
+    /// Arrow length in data units is the vector scaled by this factor
+    Factor(f32),
+}
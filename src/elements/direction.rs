@@ -0,0 +1,5 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Clockwise,
+    CounterClockwise,
+}
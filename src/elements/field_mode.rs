@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldMode {
+    /// An arrow at every data point
+    Quiver,
+    /// Trajectories integrated through the field
+    Streamlines,
+}
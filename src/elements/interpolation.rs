@@ -4,8 +4,12 @@ pub enum Interpolation {
     Linear,
     /// Smooth curve using cubic Bezier interpolation
     Bezier,
-    /// Step function - horizontal then vertical lines
+    /// Step function - horizontal then vertical lines, changing level at the next point ("post")
     Step,
+    /// Step function - vertical then horizontal lines, changing level at the current point ("pre")
+    StepPre,
+    /// Step function changing level halfway between points ("mid")
+    StepMid,
     /// Smooth spline interpolation
     Spline,
 }
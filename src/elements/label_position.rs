@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LabelPosition {
+    /// Centered within the slice
+    Inside,
+    /// Beside the slice, connected by a leader line
+    Outside,
+}
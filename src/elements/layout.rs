@@ -0,0 +1,9 @@
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Layout {
+    /// Use the configured margins as they are
+    #[default]
+    Fixed,
+    /// Grow the margins to fit the title, axis labels, tick labels and outside legend,
+    /// treating the configured margins as minimums
+    Tight,
+}
@@ -11,5 +11,11 @@ pub enum Legend {
     LeftCenterInside,
     TopCenter,
     BottomCenter,
+    /// Horizontal strip above the plot area
+    TopOutside,
+    /// Horizontal strip below the plot area
+    BottomOutside,
+    /// The inside position overlapping the fewest data points and line segments
+    Best,
     None,
 }
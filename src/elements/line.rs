@@ -3,5 +3,6 @@ pub enum Line {
     Solid,
     Dashed,
     Dotted,
+    DashDot,
     None,
 }
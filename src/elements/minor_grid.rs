@@ -1,13 +1,8 @@
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub enum MinorGrid {
+    #[default]
     None,
     XAxis,
     YAxis,
     Both,
 }
-
-impl Default for MinorGrid {
-    fn default() -> Self {
-        Self::None
-    }
-}
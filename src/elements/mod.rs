@@ -1,21 +1,47 @@
+mod angle_unit;
+mod angle_zero;
+mod arrow_head;
+mod arrow_scale;
 mod axis;
+mod direction;
+mod field_mode;
 mod grid;
 mod interpolation;
+mod label_position;
+mod layout;
 mod legend;
 mod line;
 mod marker;
 mod minor_grid;
+mod ohlc_style;
+mod palette;
 mod range;
 mod scale;
+mod slice_label;
+mod svg_format;
+mod terminal_symbols;
 mod tick;
 
+pub use angle_unit::AngleUnit;
+pub use angle_zero::AngleZero;
+pub use arrow_head::ArrowHead;
+pub use arrow_scale::ArrowScale;
 pub use axis::Axis;
+pub use direction::Direction;
+pub use field_mode::FieldMode;
 pub use grid::Grid;
 pub use interpolation::Interpolation;
+pub use label_position::LabelPosition;
+pub use layout::Layout;
 pub use legend::Legend;
 pub use line::Line;
 pub use marker::Marker;
 pub use minor_grid::MinorGrid;
+pub use ohlc_style::OhlcStyle;
+pub use palette::Palette;
 pub use range::Range;
 pub use scale::Scale;
+pub use slice_label::SliceLabel;
+pub use svg_format::SvgFormat;
+pub use terminal_symbols::TerminalSymbols;
 pub use tick::Tick;
@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OhlcStyle {
+    /// Filled body between open and close with a high-low wick
+    Candlestick,
+    /// High-low bar with an open tick on the left and a close tick on the right
+    Bar,
+}
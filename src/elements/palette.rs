@@ -0,0 +1,91 @@
+use crate::color::Color;
+
+/// Named color cycles for series, slices and other marks without an explicit color.
+///
+/// Converts into `Vec<Color>`, so any palette can be passed to a chart's `color_cycle`.
+/// A user-defined cycle is simply a `Vec<Color>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Palette {
+    /// The classic ten-color D3/matplotlib cycle, the default for every chart
+    Category10,
+    /// Tableau's current ten-color palette, softer than Category10
+    Tableau10,
+    /// The Okabe–Ito palette, distinguishable with all common forms of color blindness
+    Colorblind,
+    /// Seaborn's "deep" palette
+    Seaborn,
+    /// Four grays from black to light gray, for print; pair with a line or marker cycle
+    Grayscale,
+}
+
+const CATEGORY10: [(u8, u8, u8); 10] = [
+    (31, 119, 180),
+    (255, 127, 14),
+    (44, 160, 44),
+    (214, 39, 40),
+    (148, 103, 189),
+    (140, 86, 75),
+    (227, 119, 194),
+    (127, 127, 127),
+    (188, 189, 34),
+    (23, 190, 207),
+];
+
+const TABLEAU10: [(u8, u8, u8); 10] = [
+    (78, 121, 167),
+    (242, 142, 43),
+    (225, 87, 89),
+    (118, 183, 178),
+    (89, 161, 79),
+    (237, 201, 72),
+    (176, 122, 161),
+    (255, 157, 167),
+    (156, 117, 95),
+    (186, 176, 172),
+];
+
+// Okabe–Ito without black, so series stay distinct from axes and text
+const COLORBLIND: [(u8, u8, u8); 7] = [
+    (0, 114, 178),
+    (230, 159, 0),
+    (0, 158, 115),
+    (213, 94, 0),
+    (86, 180, 233),
+    (204, 121, 167),
+    (240, 228, 66),
+];
+
+const SEABORN: [(u8, u8, u8); 10] = [
+    (76, 114, 176),
+    (221, 132, 82),
+    (85, 168, 104),
+    (196, 78, 82),
+    (129, 114, 179),
+    (147, 120, 96),
+    (218, 139, 195),
+    (140, 140, 140),
+    (204, 185, 116),
+    (100, 181, 205),
+];
+
+const GRAYSCALE: [(u8, u8, u8); 4] = [(0, 0, 0), (85, 85, 85), (136, 136, 136), (187, 187, 187)];
+
+impl Palette {
+    /// The colors of the palette in cycle order
+    pub fn colors(self) -> Vec<Color> {
+        let rgb: &[(u8, u8, u8)] = match self {
+            Palette::Category10 => &CATEGORY10,
+            Palette::Tableau10 => &TABLEAU10,
+            Palette::Colorblind => &COLORBLIND,
+            Palette::Seaborn => &SEABORN,
+            Palette::Grayscale => &GRAYSCALE,
+        };
+        rgb.iter().map(|&rgb| rgb.into()).collect()
+    }
+}
+
+impl From<Palette> for Vec<Color> {
+    fn from(palette: Palette) -> Self {
+        palette.colors()
+    }
+}
@@ -0,0 +1,10 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SliceLabel {
+    /// Share of the total, e.g. "25.0%"
+    Percent,
+    /// Raw slice value
+    Value,
+    /// Slice name
+    Name,
+    None,
+}
@@ -0,0 +1,9 @@
+/// How SVG output is laid out as text
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum SvgFormat {
+    /// One element per line, indented by nesting depth
+    #[default]
+    Pretty,
+    /// Everything on a single line, for the smallest output
+    Compact,
+}
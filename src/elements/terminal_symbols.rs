@@ -0,0 +1,8 @@
+/// Characters used to draw data in terminal output
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TerminalSymbols {
+    /// Braille patterns with 2×4 dots per character, for the finest detail
+    Braille,
+    /// Quadrant blocks with 2×2 cells per character, for fonts without braille
+    Block,
+}
@@ -0,0 +1,119 @@
+//! Errors returned when rendering or exporting a chart.
+
+use crate::{color::ColorParseError, validation::Issue};
+use std::fmt;
+
+/// Everything that can go wrong between building a chart and writing it out
+#[derive(Debug)]
+pub enum Error {
+    /// The margins, labels and legend leave no room for the plot area
+    PlotAreaTooSmall { width: f32, height: f32 },
+    /// Data that cannot be plotted, such as NaN or infinite values, or a log-scale axis
+    /// without positive values
+    InvalidData(String),
+    /// A manual axis range that cannot be drawn
+    InvalidRange(String),
+    /// A color string that could not be parsed
+    Color(ColorParseError),
+    /// A font file or font data that could not be loaded
+    Font(String),
+    /// The SVG could not be rasterized or the image could not be encoded
+    Encoding(String),
+    /// Writing the output failed
+    Io(std::io::Error),
+    /// Issues found by `Plot::validate` while rendering in strict mode
+    Validation(Vec<Issue>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::PlotAreaTooSmall { width, height } => write!(
+                f,
+                "plot area is too small (width: {width}, height: {height}); check dimensions and margins"
+            ),
+            Error::InvalidData(message) => write!(f, "invalid data: {message}"),
+            Error::InvalidRange(message) => write!(f, "invalid range: {message}"),
+            Error::Color(err) => write!(f, "invalid color: {err}"),
+            Error::Font(message) => write!(f, "font error: {message}"),
+            Error::Encoding(message) => write!(f, "encoding error: {message}"),
+            Error::Io(err) => write!(f, "I/O error: {err}"),
+            Error::Validation(issues) => {
+                write!(f, "validation failed")?;
+                for (i, issue) in issues.iter().enumerate() {
+                    write!(f, "{} {issue}", if i == 0 { ":" } else { ";" })?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Color(err) => Some(err),
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ColorParseError> for Error {
+    fn from(err: ColorParseError) -> Self {
+        Error::Color(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// Fails on the first NaN or infinite value plotted along `axis`
+pub(crate) fn check_finite(axis: &str, values: impl IntoIterator<Item = f64>) -> Result<(), Error> {
+    match values.into_iter().find(|value| !value.is_finite()) {
+        Some(value) => Err(Error::InvalidData(format!(
+            "{axis} values must be finite, found {value}"
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Fails on the first value plotted along `axis` that is NaN, infinite, or too large to place
+/// on screen, since screen coordinates are computed in f32
+pub(crate) fn check_coordinates(
+    axis: &str,
+    values: impl IntoIterator<Item = f64> + Clone,
+) -> Result<(), Error> {
+    check_finite(axis, values.clone())?;
+    match values.into_iter().find(|value| value.abs() > f32::MAX as f64) {
+        Some(value) => Err(Error::InvalidData(format!(
+            "{axis} values must be between {:e} and {:e}, found {value:e}",
+            f32::MIN,
+            f32::MAX
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Fails when a manual range along `axis` cannot be drawn on a linear or log scale
+pub(crate) fn check_range(axis: &str, min: f32, max: f32, log: bool) -> Result<(), Error> {
+    if !min.is_finite() || !max.is_finite() {
+        return Err(Error::InvalidRange(format!(
+            "{axis} range bounds must be finite, found {min} to {max}"
+        )));
+    }
+    if min > max {
+        return Err(Error::InvalidRange(format!(
+            "{axis} range minimum {min} is greater than its maximum {max}"
+        )));
+    }
+    if log && min <= 0.0 {
+        return Err(Error::InvalidRange(format!(
+            "log-scale {axis} range must be positive, found minimum {min}"
+        )));
+    }
+    Ok(())
+}
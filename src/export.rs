@@ -0,0 +1,378 @@
+// Shared output helpers used by every chart type
+
+use crate::{elements::SvgFormat, error::Error};
+use std::fmt::Display;
+use std::io::Write;
+use svg::Document;
+
+#[cfg(feature = "png")]
+use crate::raster::{BASE_DPI, RgbaImage};
+#[cfg(any(feature = "png", feature = "pdf"))]
+use std::sync::Arc;
+#[cfg(any(feature = "png", feature = "pdf"))]
+use usvg::fontdb::Database;
+#[cfg(feature = "png")]
+use tiny_skia as skia;
+
+/// Lays out an SVG document or fragment as text in the given format.
+///
+/// The svg crate writes every element, closing tag and text node on its own line, so the
+/// layout is adjusted line by line. The content of `<text>` elements always stays on one
+/// line, since whitespace added around `<tspan>` children would shift the rendered text.
+pub(crate) fn format_svg(svg: &impl Display, format: SvgFormat) -> String {
+    let source = svg.to_string();
+    let mut output = String::with_capacity(source.len());
+    let mut depth = 0usize;
+    let mut text_depth = 0usize;
+    let mut previous_was_text = false;
+    for line in source.lines() {
+        let is_tag = line.starts_with('<');
+        let is_closing = line.starts_with("</");
+        let is_opening = is_tag && !is_closing && !line.starts_with("<!") && !line.ends_with("/>");
+        if is_closing {
+            depth = depth.saturating_sub(1);
+        }
+        if format == SvgFormat::Pretty && text_depth == 0 {
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            output.extend(std::iter::repeat_n("  ", depth));
+        } else if previous_was_text && !is_tag {
+            // Line breaks inside text content collapse to a single space when rendered
+            output.push(' ');
+        }
+        output.push_str(line);
+        if is_opening {
+            depth += 1;
+        }
+        if is_opening && (text_depth > 0 || line.starts_with("<text")) {
+            text_depth += 1;
+        } else if is_closing && text_depth > 0 {
+            text_depth -= 1;
+        }
+        previous_was_text = !is_tag;
+    }
+    if format == SvgFormat::Pretty {
+        output.push('\n');
+    }
+    output
+}
+
+/// Writes an SVG document to `writer` in the given format.
+pub(crate) fn write_svg(
+    document: &Document,
+    format: SvgFormat,
+    writer: &mut dyn Write,
+) -> Result<(), Error> {
+    writer.write_all(format_svg(document, format).as_bytes())?;
+    Ok(())
+}
+
+/// Parses an SVG document into a render tree, resolving its text against `fonts`.
+#[cfg(any(feature = "png", feature = "pdf"))]
+fn parse_tree(document: &Document, fonts: &Arc<Database>) -> Result<usvg::Tree, Error> {
+    let svg_string = document.to_string();
+
+    let opt = usvg::Options {
+        fontdb: Arc::clone(fonts),
+        ..usvg::Options::default()
+    };
+
+    usvg::Tree::from_str(&svg_string, &opt)
+        .map_err(|err| Error::Encoding(format!("failed to parse the generated SVG: {err}")))
+}
+
+/// Rasterizes an SVG document into a pixmap, scaling its size by the given factor.
+#[cfg(feature = "png")]
+fn render_pixmap(
+    document: &Document,
+    fonts: &Arc<Database>,
+    scale: f32,
+) -> Result<skia::Pixmap, Error> {
+    let tree = parse_tree(document, fonts)?;
+
+    let pixmap_size = tree
+        .size()
+        .to_int_size()
+        .scale_by(scale)
+        .ok_or_else(|| Error::Encoding(format!("invalid image size at scale {scale}")))?;
+    let mut pixmap = skia::Pixmap::new(pixmap_size.width(), pixmap_size.height())
+        .ok_or_else(|| Error::Encoding("failed to allocate the image".to_string()))?;
+
+    let render_ts = skia::Transform::from_scale(scale, scale);
+    resvg::render(&tree, render_ts, &mut pixmap.as_mut());
+
+    Ok(pixmap)
+}
+
+/// Rasterizes an SVG document into unpremultiplied RGBA samples.
+#[cfg(feature = "png")]
+pub(crate) fn render_rgba(
+    document: &Document,
+    fonts: &Arc<Database>,
+    scale: f32,
+) -> Result<RgbaImage, Error> {
+    let pixmap = render_pixmap(document, fonts, scale)?;
+    let data = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+    Ok(RgbaImage {
+        width: pixmap.width(),
+        height: pixmap.height(),
+        data,
+        dpi: BASE_DPI * scale,
+    })
+}
+
+/// Rasterizes an SVG document and encodes it as PNG, recording the resolution in a pHYs chunk.
+#[cfg(feature = "png")]
+pub(crate) fn encode_png(
+    document: &Document,
+    fonts: &Arc<Database>,
+    scale: f32,
+) -> Result<Vec<u8>, Error> {
+    let image = render_rgba(document, fonts, scale)?;
+    let encoding_error = |err: png_encoder::EncodingError| {
+        Error::Encoding(format!("failed to encode PNG: {err}"))
+    };
+
+    let mut bytes = Vec::new();
+    let mut encoder = png_encoder::Encoder::new(&mut bytes, image.width, image.height);
+    encoder.set_color(png_encoder::ColorType::Rgba);
+    encoder.set_depth(png_encoder::BitDepth::Eight);
+    // PNG stores the resolution in pixels per meter
+    let pixels_per_meter = (image.dpi / 0.0254).round() as u32;
+    encoder.set_pixel_dims(Some(png_encoder::PixelDimensions {
+        xppu: pixels_per_meter,
+        yppu: pixels_per_meter,
+        unit: png_encoder::Unit::Meter,
+    }));
+    let mut writer = encoder.write_header().map_err(encoding_error)?;
+    writer.write_image_data(&image.data).map_err(encoding_error)?;
+    writer.finish().map_err(encoding_error)?;
+    Ok(bytes)
+}
+
+/// Rasterizes an SVG document and encodes it as JPEG at the given quality from 1 to 100,
+/// recording the resolution in the JFIF header.
+///
+/// JPEG has no alpha channel, so transparent areas are flattened onto white.
+#[cfg(feature = "jpeg")]
+pub(crate) fn encode_jpeg(
+    document: &Document,
+    fonts: &Arc<Database>,
+    scale: f32,
+    quality: u8,
+) -> Result<Vec<u8>, Error> {
+    use image::codecs::jpeg::{JpegEncoder, PixelDensity};
+
+    let image = render_rgba(document, fonts, scale)?;
+    let rgb: Vec<u8> = image
+        .data
+        .chunks_exact(4)
+        .flat_map(|pixel| {
+            let alpha = pixel[3] as u16;
+            [0, 1, 2].map(|i| ((pixel[i] as u16 * alpha + 255 * (255 - alpha)) / 255) as u8)
+        })
+        .collect();
+
+    let mut bytes = Vec::new();
+    let mut encoder = JpegEncoder::new_with_quality(&mut bytes, quality.clamp(1, 100));
+    encoder.set_pixel_density(PixelDensity::dpi(image.dpi.round() as u16));
+    encoder
+        .encode(&rgb, image.width, image.height, image::ExtendedColorType::Rgb8)
+        .map_err(|err| Error::Encoding(format!("failed to encode JPEG: {err}")))?;
+    Ok(bytes)
+}
+
+/// Rasterizes an SVG document and encodes it as lossless WebP, keeping transparency.
+///
+/// WebP has no resolution field, so no DPI is recorded.
+#[cfg(feature = "webp")]
+pub(crate) fn encode_webp(
+    document: &Document,
+    fonts: &Arc<Database>,
+    scale: f32,
+) -> Result<Vec<u8>, Error> {
+    use image::codecs::webp::WebPEncoder;
+
+    let image = render_rgba(document, fonts, scale)?;
+    let mut bytes = Vec::new();
+    WebPEncoder::new_lossless(&mut bytes)
+        .encode(&image.data, image.width, image.height, image::ExtendedColorType::Rgba8)
+        .map_err(|err| Error::Encoding(format!("failed to encode WebP: {err}")))?;
+    Ok(bytes)
+}
+
+/// Converts an SVG document to a single-page vector PDF.
+///
+/// One SVG pixel becomes one PDF point, so the page has the chart's dimensions in points.
+/// Text stays selectable, with the fonts used subset and embedded in the file.
+#[cfg(feature = "pdf")]
+pub(crate) fn encode_pdf(document: &Document, fonts: &Arc<Database>) -> Result<Vec<u8>, Error> {
+    let tree = parse_tree(document, fonts)?;
+    let page = svg2pdf::PageOptions { dpi: 72.0 };
+    svg2pdf::to_pdf(&tree, svg2pdf::ConversionOptions::default(), page)
+        .map_err(|err| Error::Encoding(format!("failed to convert to PDF: {err}")))
+}
+
+#[cfg(all(test, any(feature = "png", feature = "pdf")))]
+mod tests {
+    use crate::prelude::*;
+    use usvg::roxmltree;
+
+    fn plot(format: SvgFormat) -> Plot<'static> {
+        Plot::builder()
+            .title("a < b & c")
+            .x_label("x")
+            .legend(Legend::TopRightInside)
+            .svg_format(format)
+            .data([Series::builder().name("<x> & y").data(vec![(0.0, 1.0), (1.0, 3.0)]).build()])
+            .build()
+    }
+
+    /// The text content of every `<text>` element, as a parser reads it back
+    fn texts(svg: &str) -> Vec<String> {
+        let document = roxmltree::Document::parse(svg).unwrap();
+        document
+            .descendants()
+            .filter(|node| node.has_tag_name("text"))
+            .map(|node| {
+                let content: String =
+                    node.descendants().filter(|n| n.is_text()).filter_map(|n| n.text()).collect();
+                content.trim().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn svg_string_with_markup_characters_is_well_formed() {
+        let texts = texts(&plot(SvgFormat::Pretty).to_svg_string().unwrap());
+        assert!(texts.iter().any(|t| t == "a < b & c"), "{texts:?}");
+        assert!(texts.iter().any(|t| t == "<x> & y"), "{texts:?}");
+    }
+
+    #[test]
+    fn both_formats_parse_to_the_same_text() {
+        let pretty = plot(SvgFormat::Pretty).to_svg_string().unwrap();
+        let compact = plot(SvgFormat::Compact).to_svg_string().unwrap();
+        assert!(pretty.contains('\n'));
+        assert!(!compact.contains('\n'));
+        assert_eq!(texts(&pretty), texts(&compact));
+    }
+
+    #[test]
+    fn written_svg_matches_the_string() {
+        let chart = plot(SvgFormat::Compact);
+        let mut written = Vec::new();
+        chart.write_svg(&mut written).unwrap();
+        assert_eq!(String::from_utf8(written).unwrap(), chart.to_svg_string().unwrap());
+    }
+
+    /// A chart on a fully transparent canvas
+    #[cfg(feature = "png")]
+    fn transparent() -> Plot<'static> {
+        Plot { background: Color::Rgba(0, 0, 0, 0.0), ..plot(SvgFormat::Compact) }
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn png_records_the_resolution_and_decodes_to_the_pixels() {
+        let chart = transparent();
+        let bytes = chart.to_png_bytes(2.0).unwrap();
+        let mut reader = png_encoder::Decoder::new(bytes.as_slice()).read_info().unwrap();
+        let dims = reader.info().pixel_dims.unwrap();
+        // 192 DPI in pixels per meter
+        assert_eq!((dims.xppu, dims.yppu), (7559, 7559));
+        assert_eq!(dims.unit, png_encoder::Unit::Meter);
+
+        let mut data = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut data).unwrap();
+        assert!(data == chart.to_rgba(2.0).unwrap().data);
+    }
+
+    #[cfg(feature = "jpeg")]
+    #[test]
+    fn jpeg_flattens_transparency_onto_white() {
+        let bytes = transparent().to_jpeg_bytes(1.0, 90).unwrap();
+        // JFIF density units (1 = dots per inch) and the horizontal and vertical density
+        assert_eq!(&bytes[13..18], &[1, 0, 96, 0, 96]);
+
+        let image = image::load_from_memory_with_format(&bytes, image::ImageFormat::Jpeg)
+            .unwrap()
+            .to_rgb8();
+        let corner = image.get_pixel(0, 0).0;
+        assert!(corner.iter().all(|&channel| channel >= 250), "{corner:?}");
+    }
+
+    #[cfg(feature = "webp")]
+    #[test]
+    fn webp_round_trips_losslessly() {
+        let chart = transparent();
+        let bytes = chart.to_webp_bytes(1.0).unwrap();
+        let image = image::load_from_memory_with_format(&bytes, image::ImageFormat::WebP)
+            .unwrap()
+            .to_rgba8();
+        let expected = chart.to_rgba(1.0).unwrap();
+        assert_eq!(image.dimensions(), (expected.width, expected.height));
+        assert_eq!(image.get_pixel(0, 0).0[3], 0);
+        assert!(image.into_raw() == expected.data);
+    }
+}
+
+#[cfg(all(test, feature = "bundled-font"))]
+mod bundled_font_tests {
+    use crate::{prelude::*, text::text_width};
+
+    fn plot(font: &str) -> Plot<'_> {
+        Plot::builder()
+            .title("Bundled font")
+            .x_label("x")
+            .y_label("y")
+            .font(font)
+            .legend(Legend::TopRightInside)
+            .data([Series::builder().name("series").data(vec![(0.0, 1.0), (1.0, 3.0)]).build()])
+            .build()
+    }
+
+    /// 64-bit FNV-1a, which unlike the std hasher is fixed across Rust versions
+    fn fnv1a(bytes: &[u8]) -> u64 {
+        bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+    }
+
+    #[test]
+    fn png_output_matches_the_golden_hash() {
+        // Update when a rendering change is intended: the hash pins the bundled-font output
+        // across runs and machines, not just within one process
+        let png = plot("Times New Roman").to_png_bytes(1.0).unwrap();
+        assert_eq!(format!("{:016x}", fnv1a(&png)), "96216dd322b33180");
+    }
+
+    #[test]
+    fn every_family_uses_the_bundled_font() {
+        let families = ["Times New Roman", "Arial, sans-serif", "monospace", "No Such Font"];
+        let widths: Vec<f32> = families.iter().map(|f| text_width("Quill 123", f, 12.0)).collect();
+        assert!(widths.iter().all(|&w| w == widths[0]), "{widths:?}");
+
+        let reference = plot(families[0]).to_png_bytes(1.0).unwrap();
+        for family in &families[1..] {
+            assert!(plot(family).to_png_bytes(1.0).unwrap() == reference, "{family}");
+        }
+    }
+
+    #[test]
+    fn text_is_drawn() {
+        // Same layout, with the title painted in the background color
+        let visible = plot("serif").to_rgba(1.0).unwrap();
+        let mut hidden = plot("serif");
+        hidden.title_config.color = Color::White;
+        assert!(visible.data != hidden.to_rgba(1.0).unwrap().data);
+    }
+}
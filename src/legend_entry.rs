@@ -0,0 +1,76 @@
+use crate::{
+    color::Color,
+    elements::{Line, Marker},
+    ohlc::OhlcSeries,
+    series::{Series, SeriesStyle},
+    vector_field::VectorField,
+};
+use bon::Builder;
+
+/// A legend item and the glyph drawn beside its name.
+///
+/// Charts build one for each series shown in the legend; custom entries can be added
+/// to a chart's `legend_entries` to label things that are not series, such as
+/// reference lines or annotations.
+#[derive(Clone, Debug, Builder)]
+pub struct LegendEntry<'a> {
+    #[builder(default = "")]
+    pub name: &'a str,
+    #[builder(default = Color::Black, into)]
+    pub color: Color,
+    #[builder(default = Line::Solid)]
+    pub line: Line,
+    #[builder(default = 1.0)]
+    pub line_width: f32,
+    #[builder(default = Marker::None)]
+    pub marker: Marker,
+    #[builder(default = 1.0)]
+    pub marker_size: f32,
+    /// Fills the glyph's background, as for filled series or pie slices
+    #[builder(into)]
+    pub fill: Option<Color>,
+    #[builder(default = 1.0)]
+    pub fill_opacity: f32,
+}
+
+impl<'a> LegendEntry<'a> {
+    /// A solid block of color, used for bars, slices and other area marks
+    pub fn patch(name: &'a str, color: Color) -> Self {
+        LegendEntry::builder()
+            .name(name)
+            .color(color.clone())
+            .line(Line::None)
+            .fill(color)
+            .build()
+    }
+
+    /// The entry for a series drawn with `style`, which the chart resolves from its cycles
+    pub fn from_series<T>(series: &Series<'a, T>, style: &SeriesStyle) -> Self {
+        LegendEntry {
+            name: series.name,
+            color: style.color.clone(),
+            line: style.line,
+            line_width: series.line_width,
+            marker: style.marker,
+            marker_size: series.marker_size,
+            fill: series.fill.clone(),
+            fill_opacity: series.fill_opacity,
+        }
+    }
+}
+
+impl<'a, T> From<&OhlcSeries<'a, T>> for LegendEntry<'a> {
+    fn from(series: &OhlcSeries<'a, T>) -> Self {
+        LegendEntry::patch(series.name, series.up_color.clone())
+    }
+}
+
+impl<'a, T> From<&VectorField<'a, T>> for LegendEntry<'a> {
+    fn from(field: &VectorField<'a, T>) -> Self {
+        LegendEntry::builder()
+            .name(field.name)
+            .color(field.color.clone())
+            .line_width(field.line_width)
+            .build()
+    }
+}
@@ -73,11 +73,28 @@
 // - if y_min and x_min are the same use one number for the origin e.g. (0.0 y axis, 0.0 x axis) is rendered as one 0.0 at vertex of x-y axis
 // - Real testing of all the enum options for settings
 
+pub mod backend;
 pub mod color;
+pub mod colormap;
 pub mod draw;
+pub mod error;
+mod export;
+pub mod legend_entry;
+pub mod ohlc;
+pub mod pie;
 pub mod plot;
+pub mod polar;
+#[cfg(feature = "png")]
+pub mod raster;
+#[cfg(any(feature = "png", feature = "pdf"))]
+pub mod render_context;
 pub mod series;
+pub mod slice;
+mod terminal;
+pub mod text;
 pub mod traits;
+pub mod validation;
+pub mod vector_field;
 pub mod elements;
 pub mod style;
 
@@ -0,0 +1,32 @@
+use crate::{color::Color, elements::OhlcStyle};
+use bon::Builder;
+
+/// A financial price series where each point is `(time, open, high, low, close)`.
+#[derive(Clone, Builder)]
+pub struct OhlcSeries<'a, T = f32> {
+    pub data: Vec<(T, T, T, T, T)>,
+    #[builder(default = "")]
+    pub name: &'a str,
+    #[builder(default = OhlcStyle::Candlestick)]
+    pub style: OhlcStyle,
+    /// Color of periods that close at or above their open
+    #[builder(default = Color::Green, into)]
+    pub up_color: Color,
+    /// Color of periods that close below their open
+    #[builder(default = Color::Red, into)]
+    pub down_color: Color,
+    /// Body width as a fraction of the smallest spacing between periods
+    #[builder(default = 0.6)]
+    pub body_width: f32,
+    #[builder(default = 1.0)]
+    pub line_width: f32,
+    /// Traded volume per period, drawn as bars along the bottom of the plot area
+    pub volume: Option<Vec<T>>,
+    /// Height of the tallest volume bar as a fraction of the plot area height; an automatic
+    /// y range is extended downwards so that prices stay above it
+    #[builder(default = 0.2)]
+    pub volume_height: f32,
+    /// List this series in the legend
+    #[builder(default = true)]
+    pub show_in_legend: bool,
+}
@@ -0,0 +1,354 @@
+use crate::{
+    backend::{Backend, Rect},
+    color::Color,
+    error::{Error, check_finite},
+    draw::{ChartFrame, draw_pie, slice_label_text},
+    elements::*,
+    legend_entry::LegendEntry,
+    slice::Slice,
+    style::*,
+    text::text_width,
+    traits::Chart,
+};
+use bon::Builder;
+
+/// A pie or donut chart showing each slice's share of the total.
+///
+/// Unlike [`Plot`](crate::plot::Plot), a pie chart has no axes, ticks or grid.
+#[derive(Builder)]
+pub struct PieChart<'a> {
+    // --- Chart Settings ---
+    #[builder(default = (800, 600))]
+    pub dimensions: (i32, i32),
+    #[builder(default = "")]
+    pub title: &'a str,
+    #[builder(default = Legend::None)]
+    pub legend: Legend,
+    /// Angle of the first slice edge in degrees, counter-clockwise from 3 o'clock
+    #[builder(default = 90.0)]
+    pub start_angle: f32,
+    #[builder(default = Direction::Clockwise)]
+    pub direction: Direction,
+    /// Radius of the hole as a fraction of the outer radius; 0.0 draws a full pie
+    #[builder(default = 0.0)]
+    pub inner_radius: f32,
+    #[builder(default = SliceLabel::Percent)]
+    pub slice_label: SliceLabel,
+    #[builder(default = LabelPosition::Inside)]
+    pub label_position: LabelPosition,
+    #[builder(default = "Times New Roman")]
+    pub font: &'a str,
+    /// Fill colors given to slices without an explicit color, by slice position.
+    /// Accepts a [`Palette`] or any `Vec<Color>`
+    #[builder(default = Palette::Category10.colors(), into)]
+    pub color_cycle: Vec<Color>,
+    /// Text layout of SVG output: indented or on a single line
+    #[builder(default)]
+    pub svg_format: SvgFormat,
+
+    // --- Style Configurations ---
+    /// Fill of the whole image
+    #[builder(default = Color::White, into)]
+    pub background: Color,
+    #[builder(default = Margin::default())]
+    pub margin: Margin,
+    #[builder(default = TitleConfig::default())]
+    pub title_config: TitleConfig,
+    #[builder(default = LegendConfig::default())]
+    pub legend_config: LegendConfig,
+    #[builder(default = PieConfig::default())]
+    pub pie_config: PieConfig,
+
+    // --- Data ---
+    pub slices: Vec<Slice<'a>>,
+    /// Legend-only entries listed after the chart's own
+    #[builder(default)]
+    pub legend_entries: Vec<LegendEntry<'a>>,
+}
+
+impl<'a> PieChart<'a> {
+    /// Resolves the fill color of every slice, taking the slice's place in the color cycle
+    /// when it has none.
+    fn slice_colors(&self) -> Vec<Color> {
+        self.slices
+            .iter()
+            .enumerate()
+            .map(|(i, slice)| match &slice.color {
+                Some(color) => color.clone(),
+                None if self.color_cycle.is_empty() => Color::Black,
+                None => self.color_cycle[i % self.color_cycle.len()].clone(),
+            })
+            .collect()
+    }
+
+    /// The settings shared with every other chart type
+    fn frame(&self) -> ChartFrame<'_> {
+        ChartFrame {
+            dimensions: self.dimensions,
+            title: self.title,
+            font: self.font,
+            background: &self.background,
+            margin: &self.margin,
+            legend: self.legend,
+            title_config: &self.title_config,
+            legend_config: &self.legend_config,
+            color_cycle: &self.color_cycle,
+            legend_entries: &self.legend_entries,
+        }
+    }
+
+    /// Fails with the first color that holds an unparsable string
+    fn check_colors(&self) -> Result<(), Error> {
+        let styles = [
+            &self.pie_config.label_color,
+            &self.pie_config.border_color,
+            &self.pie_config.leader_color,
+        ];
+        let slices = self.slices.iter().flat_map(|slice| &slice.color);
+        self.frame().check_colors(styles.into_iter().chain(slices))
+    }
+
+    /// Draws the chart on any backend, such as a GUI canvas.
+    ///
+    /// The layout is complete before the first primitive is drawn, so nothing reaches the
+    /// backend when an error is returned.
+    pub fn render(&self, backend: &mut dyn Backend) -> Result<(), Error> {
+        self.check_colors()?;
+        check_finite("slice", self.slices.iter().map(|slice| slice.value))?;
+
+        // Calculate legend dimensions
+        let colors = self.slice_colors();
+        let legend_entries: Vec<LegendEntry> = self
+            .slices
+            .iter()
+            .zip(colors.iter())
+            .filter(|(s, _)| s.show_in_legend)
+            .map(|(s, color)| LegendEntry::patch(s.name, color.clone()))
+            .chain(self.legend_entries.iter().cloned())
+            .collect();
+        let frame = self.frame();
+        let layout = frame.layout(self.margin.clone(), frame.legend_layout(&legend_entries))?;
+        let Rect {
+            x: area_x_start,
+            y: area_y_start,
+            width: area_width,
+            height: area_height,
+            ..
+        } = layout.plot_area;
+
+        // Reserve room for exploded slices and outside labels
+        let max_explode = self
+            .slices
+            .iter()
+            .map(|s| s.explode.max(0.0))
+            .fold(0.0f32, f32::max);
+        let total: f64 = self.slices.iter().map(|s| s.value.max(0.0)).sum();
+        let (label_room_x, label_room_y) = if self.label_position == LabelPosition::Outside
+            && self.slice_label != SliceLabel::None
+            && total > 0.0
+        {
+            let max_label_width = self
+                .slices
+                .iter()
+                .map(|s| {
+                    text_width(
+                        &slice_label_text(s, total, self.slice_label),
+                        self.font,
+                        self.pie_config.label_font_size,
+                    )
+                })
+                .fold(0.0f32, f32::max);
+            let leader = self.pie_config.leader_length * 1.6 + 3.0;
+            (leader + max_label_width, leader + self.pie_config.label_font_size)
+        } else {
+            (0.0, 0.0)
+        };
+        let radius = ((area_width / 2.0 - label_room_x).min(area_height / 2.0 - label_room_y)
+            / (1.0 + max_explode))
+            .max(0.0);
+        let cx = area_x_start + area_width / 2.0;
+        let cy = area_y_start + area_height / 2.0;
+
+        // --- Background ---
+        frame.draw_background(backend);
+
+        // --- Draw Title ---
+        frame.draw_title(backend, &layout);
+
+        // --- Draw Slices ---
+        draw_pie(
+            backend,
+            &self.slices,
+            &colors,
+            self.font,
+            &self.pie_config,
+            self.slice_label,
+            self.label_position,
+            cx,
+            cy,
+            radius,
+            self.inner_radius,
+            self.start_angle,
+            self.direction,
+        );
+
+        // --- Legend Drawing ---
+        frame.draw_legend(backend, &legend_entries, &layout, || {
+            // Avoid the outline of the pie
+            let outline = (0..=64)
+                .map(|i| {
+                    let angle = i as f32 / 64.0 * std::f32::consts::TAU;
+                    (cx + radius * angle.cos(), cy - radius * angle.sin())
+                })
+                .collect();
+            vec![outline]
+        });
+        Ok(())
+    }
+}
+
+impl Chart for PieChart<'_> {
+    fn render(&self, backend: &mut dyn Backend) -> Result<(), Error> {
+        PieChart::render(self, backend)
+    }
+
+    fn svg_format(&self) -> SvgFormat {
+        self.svg_format
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{
+        Role, Segment,
+        recorder::{Recorder, rounded},
+    };
+    use Segment::*;
+
+    /// Slice outlines of a 400 by 400 chart without margins or labels, so the pie is
+    /// centered on (200, 200) with a radius of 200 unless slices are exploded
+    fn slice_paths(chart: PieChart) -> Vec<Vec<Vec<Segment>>> {
+        let chart = PieChart {
+            dimensions: (400, 400),
+            margin: Margin::new(0.0, 0.0, 0.0, 0.0),
+            slice_label: SliceLabel::None,
+            ..chart
+        };
+        let mut recorder = Recorder::default();
+        chart.render(&mut recorder).unwrap();
+        (0..chart.slices.len())
+            .map(|i| recorder.paths(Role::Slice(i)).into_iter().map(rounded).collect())
+            .collect()
+    }
+
+    fn slice(value: f64) -> Slice<'static> {
+        Slice::builder().value(value).build()
+    }
+
+    fn arc(radius: f32, large_arc: bool, sweep: bool, to: (f32, f32)) -> Segment {
+        ArcTo { radius, large_arc, sweep, to }
+    }
+
+    #[test]
+    fn single_slice_is_a_full_circle_in_two_halves() {
+        let paths = slice_paths(PieChart::builder().slices(vec![slice(5.0)]).build());
+        // Starts at 12 o'clock and runs clockwise
+        let expected = vec![
+            MoveTo((200.0, 0.0)),
+            arc(200.0, false, true, (200.0, 400.0)),
+            arc(200.0, false, true, (200.0, 0.0)),
+            Close,
+        ];
+        assert_eq!(paths, [[expected]]);
+    }
+
+    #[test]
+    fn slices_are_wedges_from_the_start_angle() {
+        let chart = PieChart::builder().slices(vec![slice(1.0), slice(3.0)]).build();
+        let paths = slice_paths(chart);
+        let quarter = vec![
+            MoveTo((200.0, 0.0)),
+            arc(200.0, false, true, (400.0, 200.0)),
+            LineTo((200.0, 200.0)),
+            Close,
+        ];
+        let rest = vec![
+            MoveTo((400.0, 200.0)),
+            arc(200.0, true, true, (200.0, 0.0)),
+            LineTo((200.0, 200.0)),
+            Close,
+        ];
+        assert_eq!(paths, [[quarter], [rest]]);
+
+        // Counter-clockwise from 3 o'clock
+        let chart = PieChart::builder()
+            .start_angle(0.0)
+            .direction(Direction::CounterClockwise)
+            .slices(vec![slice(1.0), slice(3.0)])
+            .build();
+        let first = &slice_paths(chart)[0][0];
+        assert_eq!(first[..2], [MoveTo((400.0, 200.0)), arc(200.0, false, false, (200.0, 0.0))]);
+    }
+
+    #[test]
+    fn donut_cuts_out_the_inner_radius() {
+        let chart = PieChart::builder().inner_radius(0.5).slices(vec![slice(1.0)]).build();
+        let expected = vec![
+            MoveTo((200.0, 0.0)),
+            arc(200.0, false, true, (200.0, 400.0)),
+            arc(200.0, false, true, (200.0, 0.0)),
+            Close,
+            // The hole runs the other way round
+            MoveTo((200.0, 100.0)),
+            arc(100.0, false, false, (200.0, 300.0)),
+            arc(100.0, false, false, (200.0, 100.0)),
+            Close,
+        ];
+        assert_eq!(slice_paths(chart), [[expected]]);
+
+        let halves = vec![slice(1.0), slice(1.0)];
+        let chart = PieChart::builder().inner_radius(0.5).slices(halves).build();
+        let right_half = vec![
+            MoveTo((200.0, 0.0)),
+            arc(200.0, false, true, (200.0, 400.0)),
+            LineTo((200.0, 300.0)),
+            arc(100.0, false, false, (200.0, 100.0)),
+            Close,
+        ];
+        assert_eq!(slice_paths(chart)[0], [right_half]);
+    }
+
+    #[test]
+    fn exploded_slice_moves_out_along_its_bisector() {
+        let exploded = Slice::builder().value(1.0).explode(0.25).build();
+        let paths = slice_paths(PieChart::builder().slices(vec![exploded, slice(1.0)]).build());
+        // The radius shrinks to 160 to keep the exploded slice on the canvas
+        let right = vec![
+            MoveTo((240.0, 40.0)),
+            arc(160.0, false, true, (240.0, 360.0)),
+            LineTo((240.0, 200.0)),
+            Close,
+        ];
+        let left = vec![
+            MoveTo((200.0, 360.0)),
+            arc(160.0, false, true, (200.0, 40.0)),
+            LineTo((200.0, 200.0)),
+            Close,
+        ];
+        assert_eq!(paths, [[right], [left]]);
+    }
+
+    #[test]
+    fn zero_and_negative_slices_are_skipped() {
+        let chart = PieChart::builder()
+            .slices(vec![slice(1.0), slice(0.0), slice(-2.0), slice(1.0)])
+            .build();
+        let paths = slice_paths(chart);
+        assert_eq!(paths.iter().map(Vec::len).collect::<Vec<_>>(), [1, 0, 0, 1]);
+        assert_eq!(paths[3][0][0], MoveTo((200.0, 400.0)));
+
+        let empty = PieChart::builder().slices(vec![slice(0.0), slice(-1.0)]).build();
+        assert!(slice_paths(empty).iter().all(Vec::is_empty));
+    }
+}
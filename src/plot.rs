@@ -1,23 +1,23 @@
 use crate::{
-    traits::PlotValue,
+    backend::{Backend, Clip, Rect, Role, ShapeStyle},
+    color::Color,
+    error::{Error, check_coordinates, check_range},
+    traits::{Chart, PlotValue},
+    validation::{Issue, series_label},
     draw::{
-        draw_axis_lines, draw_data_series, draw_legend, draw_ticks_and_grids, draw_title,
-        draw_x_label, draw_y_label,
+        ChartFrame, calculate_linear_ticks, calculate_log_ticks, draw_axis_lines,
+        draw_data_series, draw_ohlc_series, draw_ticks_and_grids, draw_vector_fields,
+        draw_x_label, draw_y_label, major_ticks, scale_exponent, tick_label, tick_label_width,
     },
     elements::*,
-    series::Series,
+    legend_entry::LegendEntry,
+    ohlc::OhlcSeries,
+    series::{Series, SeriesStyle, series_styles},
+    terminal::{Canvas, Frame},
+    vector_field::VectorField,
     style::*,
 };
 use bon::Builder;
-use svg::{
-    Document,
-    node::element::{ClipPath, Definitions, Rectangle},
-};
-
-#[cfg(feature = "png")]
-use resvg::usvg;
-#[cfg(feature = "png")]
-use tiny_skia as skia;
 
 #[derive(Builder)]
 pub struct Plot<'a, T: PlotValue = f32, const N: usize = 1> {
@@ -50,8 +50,33 @@ pub struct Plot<'a, T: PlotValue = f32, const N: usize = 1> {
     pub y_scale: Scale,
     #[builder(default = "Times New Roman")]
     pub font: &'a str,
+    /// Colors given in turn to series without an explicit color; series are black when empty.
+    /// Accepts a [`Palette`] or any `Vec<Color>`
+    #[builder(default = Palette::Category10.colors(), into)]
+    pub color_cycle: Vec<Color>,
+    /// Line styles given in turn to solid-line series, e.g. for grayscale output
+    #[builder(default)]
+    pub line_cycle: Vec<Line>,
+    /// Markers given in turn to series without markers
+    #[builder(default)]
+    pub marker_cycle: Vec<Marker>,
+    /// How the margins around the plot area are determined
+    #[builder(default)]
+    pub layout: Layout,
+    /// Refuse to render when `validate` reports any issue, including warnings
+    #[builder(default = false)]
+    pub strict: bool,
+    /// Text layout of SVG output: indented or on a single line
+    #[builder(default)]
+    pub svg_format: SvgFormat,
 
     // --- Style Configurations ---
+    /// Fill of the whole image
+    #[builder(default = Color::White, into)]
+    pub background: Color,
+    /// Fill of the plot area, drawn beneath the grid
+    #[builder(into)]
+    pub plot_background: Option<Color>,
     #[builder(default = Margin::default())]
     pub margin: Margin,
     #[builder(default = TitleConfig::default())]
@@ -71,109 +96,513 @@ pub struct Plot<'a, T: PlotValue = f32, const N: usize = 1> {
 
     // --- Data ---
     pub data: [Series<'a, T>; N],
+    /// Candlestick and OHLC bar series drawn on the same axes
+    #[builder(default)]
+    pub ohlc: Vec<OhlcSeries<'a, T>>,
+    /// Quiver and streamline fields drawn on the same axes
+    #[builder(default)]
+    pub vector_fields: Vec<VectorField<'a, T>>,
+    /// Legend-only entries listed after the chart's own
+    #[builder(default)]
+    pub legend_entries: Vec<LegendEntry<'a>>,
 }
 
 impl<'a, T: PlotValue, const N: usize> Plot<'a, T, N> {
-    /// Saves the plot as an SVG file
-    pub fn to_svg(&self, filename: &str) -> Result<(), std::io::Error> {
-        let document = self.plot()?;
-        svg::save(filename, &document)?;
-        Ok(())
+    /// Renders the plot as text for a terminal, drawing the data with braille or block
+    /// characters.
+    ///
+    /// Ranges, ticks, colors and legend entries match the SVG output. The data is drawn by
+    /// rendering the plot onto the character canvas as a [`Backend`]: lines, curves, stems,
+    /// markers, OHLC wicks and outlines and vector field lines are drawn, while fills, arrow
+    /// heads and volume bars are left out.
+    pub fn to_terminal(&self, config: &TerminalConfig) -> Result<String, Error> {
+        self.check()?;
+        let (x_bounds, y_bounds) = self.axis_ranges();
+        // Space ticks as if each character were 8 by 16 pixels
+        let (x_ticks, y_ticks) = self.ticks(
+            x_bounds,
+            y_bounds,
+            config.width as f32 * 8.0,
+            config.height as f32 * 16.0,
+        );
+        let styles = series_styles(
+            &self.data,
+            &self.color_cycle,
+            &self.line_cycle,
+            &self.marker_cycle,
+        );
+        let frame = Frame {
+            title: self.title,
+            x_label: terminal_axis_label(self.x_label, &x_ticks, self.x_scale),
+            y_label: terminal_axis_label(self.y_label, &y_ticks, self.y_scale),
+            x_ticks: terminal_ticks(&x_ticks, x_bounds, self.x_scale),
+            y_ticks: terminal_ticks(&y_ticks, y_bounds, self.y_scale),
+            legend: if self.legend == Legend::None {
+                Vec::new()
+            } else {
+                self.legend_items(&styles)
+            },
+            color: config.color,
+        };
+        let (columns, rows) = frame.canvas_size(config.width, config.height)?;
+        let mut canvas = Canvas::new(columns, rows, config.symbols);
+
+        // The plot area fills the canvas at the same 8 by 16 pixels per character, since the
+        // margins, title and legend are laid out around it as text
+        let canvas_frame = ChartFrame {
+            dimensions: ((columns * 8) as i32, (rows * 16) as i32),
+            margin: &Margin::new(0.0, 0.0, 0.0, 0.0),
+            legend: Legend::None,
+            ..self.frame()
+        };
+        self.render_in(&mut canvas, canvas_frame, Layout::Fixed)?;
+        Ok(frame.render(&canvas, config.width))
+    }
+
+    /// Replaces every style setting with those of `theme`, keeping the data, labels,
+    /// ranges, scales, legend placement, layout and margins.
+    pub fn with_theme(mut self, theme: &Theme<'a>) -> Self {
+        self.background = theme.background.clone();
+        self.plot_background = theme.plot_background.clone();
+        self.font = theme.font;
+        self.color_cycle = theme.color_cycle.clone();
+        self.line_cycle = theme.line_cycle.clone();
+        self.marker_cycle = theme.marker_cycle.clone();
+        self.axis = theme.axis;
+        self.tick = theme.tick;
+        self.grid = theme.grid;
+        self.title_config = theme.title_config.clone();
+        self.x_label_config = theme.x_label_config.clone();
+        self.y_label_config = theme.y_label_config.clone();
+        self.tick_config = theme.tick_config.clone();
+        self.legend_config = theme.legend_config.clone();
+        self.axis_config = theme.axis_config.clone();
+        self.grid_config = theme.grid_config.clone();
+        self
+    }
+
+    /// Collects every x value that contributes to the automatic x range.
+    fn x_values(&self) -> Vec<T> {
+        let series_x = self.data.iter().flat_map(|s| s.data.iter().map(|&(x, _)| x));
+        let mut values: Vec<T> = series_x.collect();
+        for series in &self.ohlc {
+            values.extend(series.data.iter().map(|&(t, ..)| t));
+            // Pad by half a period on both ends so the outer bodies stay inside the plot area
+            let min_gap = series
+                .data
+                .windows(2)
+                .map(|w| (w[1].0.to_f32() - w[0].0.to_f32()).abs())
+                .filter(|d| *d > 0.0)
+                .fold(f32::INFINITY, f32::min);
+            if let (Some(first), Some(last), true) =
+                (series.data.first(), series.data.last(), min_gap.is_finite())
+            {
+                values.push(T::from_f32(first.0.to_f32() - min_gap / 2.0));
+                values.push(T::from_f32(last.0.to_f32() + min_gap / 2.0));
+            }
+        }
+        for field in &self.vector_fields {
+            values.extend(field.data.iter().map(|&(x, ..)| x));
+            if let ArrowScale::Factor(factor) = field.scale {
+                values.extend(
+                    field
+                        .data
+                        .iter()
+                        .map(|&(x, _, u, _)| T::from_f32(x.to_f32() + u.to_f32() * factor)),
+                );
+            }
+        }
+        values
+    }
+
+    /// Collects every y value that contributes to the automatic y range.
+    fn y_values(&self) -> Vec<T> {
+        let series_y = self.data.iter().flat_map(|s| {
+            // Stems and fills extend down to their baseline
+            let baseline = (!s.data.is_empty() && (s.stem || s.fill.is_some()))
+                .then(|| s.baseline.unwrap_or(T::from_f32(0.0)));
+            s.data.iter().map(|&(_, y)| y).chain(baseline)
+        });
+        let ohlc_y = self
+            .ohlc
+            .iter()
+            .flat_map(|s| s.data.iter().flat_map(|&(_, _, high, low, _)| [high, low]));
+        let field_y = self.vector_fields.iter().flat_map(|f| {
+            let factor = match f.scale {
+                ArrowScale::Factor(factor) => Some(factor),
+                _ => None,
+            };
+            f.data
+                .iter()
+                .flat_map(move |&(_, y, _, v)| {
+                    [Some(y), factor.map(|k| T::from_f32(y.to_f32() + v.to_f32() * k))]
+                })
+                .flatten()
+        });
+        series_y.chain(ohlc_y).chain(field_y).collect()
+    }
+
+    /// Calculates the major x and y tick values for the given ranges and plot area size.
+    fn ticks(
+        &self,
+        (x_min, x_max): (T, T),
+        (y_min, y_max): (T, T),
+        plot_area_width: f32,
+        plot_area_height: f32,
+    ) -> (Vec<f32>, Vec<f32>) {
+        let num_x_ticks = (plot_area_width / self.tick_config.density_x).max(2.0) as usize;
+        let num_y_ticks = (plot_area_height / self.tick_config.density_y).max(2.0) as usize;
+
+        let x_ticks = if self.x_scale == Scale::Log {
+            calculate_log_ticks(x_min.to_f32(), x_max.to_f32())
+        } else {
+            calculate_linear_ticks(x_min.to_f32(), x_max.to_f32(), num_x_ticks)
+        };
+
+        let y_ticks = if self.y_scale == Scale::Log {
+            calculate_log_ticks(y_min.to_f32(), y_max.to_f32())
+        } else {
+            calculate_linear_ticks(y_min.to_f32(), y_max.to_f32(), num_y_ticks)
+        };
+        (x_ticks, y_ticks)
+    }
+
+    /// Measures the label drawn for each major tick, paired with its tick value.
+    fn tick_label_widths(&self, ticks: &[f32], scale: Scale) -> Vec<(f32, f32)> {
+        let scale_factor = 10.0_f32.powi(scale_exponent(ticks, scale));
+        major_ticks(ticks, scale)
+            .into_iter()
+            .map(|tick| {
+                let label = tick_label(tick, scale, scale_factor);
+                (tick, tick_label_width(&label, self.font, self.tick_config.font_size))
+            })
+            .collect()
+    }
+
+    /// Horizontal room taken by the y tick marks and labels left of the plot area.
+    fn y_tick_label_extent(&self, y_ticks: &[f32]) -> f32 {
+        if self.tick == Tick::None {
+            return 0.0;
+        }
+        let widest_label = self
+            .tick_label_widths(y_ticks, self.y_scale)
+            .into_iter()
+            .map(|(_, width)| width)
+            .fold(0.0f32, f32::max);
+        self.tick_config.length + self.tick_config.text_padding + widest_label
+    }
+
+    /// Computes margins that fit the title, axis labels, tick labels and scale exponent
+    /// labels, never going below the configured margins.
+    fn tight_margin(
+        &self,
+        x_ticks: &[f32],
+        y_ticks: &[f32],
+        (x_min, x_max): (T, T),
+        plot_area_width: f32,
+    ) -> Margin {
+        const PADDING: f32 = 6.0;
+        let tick = &self.tick_config;
+        let has_ticks = self.tick != Tick::None;
+        let exponent_shown = |ticks: &[f32], scale: Scale| {
+            has_ticks
+                && matches!(scale, Scale::Scientific | Scale::Engineering)
+                && scale_exponent(ticks, scale) != 0
+        };
+        // Outward ticks on the top and right edges of a box axis
+        let box_tick_length = if has_ticks && self.tick == Tick::Outward && self.axis == Axis::Box {
+            tick.length
+        } else {
+            0.0
+        };
+
+        // Top: the y scale exponent sits above the plot area, with the title above it
+        let mut above_plot = box_tick_length;
+        if exponent_shown(y_ticks, self.y_scale) {
+            above_plot = above_plot.max(tick.font_size * 1.2 + tick.text_padding);
+        }
+        let top = if self.title.is_empty() {
+            above_plot + PADDING
+        } else {
+            // The title is centered at half the top margin
+            2.0 * (self.title_config.font_size * 0.6 + above_plot + PADDING)
+        };
+
+        // Bottom: tick labels and the x scale exponent, then the x axis label
+        let mut below_plot = if has_ticks {
+            let outward = if self.tick == Tick::Outward { tick.length } else { 0.0 };
+            outward.max(tick.font_size * 1.6 + 5.0)
+        } else {
+            0.0
+        };
+        if exponent_shown(x_ticks, self.x_scale) {
+            below_plot = below_plot.max(tick.font_size * 2.2 + tick.text_padding * 2.0);
+        }
+        let bottom = if self.x_label.is_empty() {
+            below_plot + PADDING
+        } else {
+            // The x label is centered at 60% of the bottom margin
+            let label_half = self.x_label_config.font_size * 0.6;
+            ((below_plot + PADDING + label_half) / 0.6).max((label_half + PADDING) / 0.4)
+        };
+
+        // Left: y tick labels, then the rotated y axis label
+        let y_extent = self.y_tick_label_extent(y_ticks);
+        let mut left = if self.y_label.is_empty() {
+            y_extent + PADDING
+        } else {
+            let font_size = self.y_label_config.font_size;
+            (y_extent + font_size * 1.4 + PADDING).max(font_size * 2.0)
+        };
+
+        // Left and right: x tick labels centered on the plot area edges overhang it
+        let mut right = box_tick_length + PADDING;
+        if has_ticks {
+            let to_fraction = |value: f32| {
+                let (min, max) = (x_min.to_f32(), x_max.to_f32());
+                if self.x_scale == Scale::Log && min > 0.0 && max > 0.0 && value > 0.0 {
+                    (value.log10() - min.log10()) / (max.log10() - min.log10())
+                } else {
+                    (value - min) / (max - min)
+                }
+            };
+            for (value, width) in self.tick_label_widths(x_ticks, self.x_scale) {
+                let fraction = to_fraction(value);
+                if !(-0.001..=1.001).contains(&fraction) {
+                    continue;
+                }
+                let screen_x = fraction * plot_area_width;
+                left = left.max(width / 2.0 - screen_x + PADDING);
+                right = right.max(width / 2.0 - (plot_area_width - screen_x) + PADDING);
+            }
+        }
+
+        Margin::new(
+            top.max(self.margin.top),
+            bottom.max(self.margin.bottom),
+            left.max(self.margin.left),
+            right.max(self.margin.right),
+        )
     }
 
-    /// Saves the plot as a PNG file, scaling the size of the image by the given scale factor.
+    /// Projects the drawn data into screen space as polylines, for legend placement.
     ///
-    /// This method is only available when the "png" feature is enabled.
-    #[cfg(feature = "png")]
-    pub fn to_png(&self, filename: &str, scale: f32) -> Result<(), Box<dyn std::error::Error>> {
-        let document = self.plot()?;
-        let svg_string = document.to_string();
+    /// Connected series become one polyline each, stems and OHLC ranges become two point
+    /// segments, and unconnected markers and arrows become single points.
+    fn screen_polylines<Fx, Fy>(&self, map_x: &Fx, map_y: &Fy) -> Vec<Vec<(f32, f32)>>
+    where
+        Fx: Fn(T) -> f32,
+        Fy: Fn(T) -> f32,
+    {
+        let mut polylines = Vec::new();
+        for series in &self.data {
+            let points = series.data.iter().map(|&(x, y)| (map_x(x), map_y(y)));
+            if series.stem {
+                let baseline = map_y(series.baseline.unwrap_or(T::from_f32(0.0)));
+                polylines.extend(points.map(|(x, y)| vec![(x, baseline), (x, y)]));
+            } else if series.line != Line::None {
+                polylines.push(points.collect());
+            } else {
+                polylines.extend(points.map(|point| vec![point]));
+            }
+        }
+        for series in &self.ohlc {
+            polylines.extend(series.data.iter().map(|&(t, _, high, low, _)| {
+                vec![(map_x(t), map_y(high)), (map_x(t), map_y(low))]
+            }));
+        }
+        for field in &self.vector_fields {
+            polylines.extend(field.data.iter().map(|&(x, y, ..)| vec![(map_x(x), map_y(y))]));
+        }
+        polylines
+    }
 
-        let mut opt = usvg::Options::default();
-        opt.fontdb_mut().load_system_fonts();
+    /// Looks for likely mistakes that rendering would otherwise hide: non-positive values on
+    /// a log scale, empty manual ranges, auxiliary arrays of the wrong length, series entirely
+    /// outside a manual range and repeated legend names.
+    pub fn validate(&self) -> Vec<Issue> {
+        let mut issues = Vec::new();
 
-        let tree = usvg::Tree::from_str(&svg_string, &opt)?;
+        // Every plotted (x, y) point per series, with auxiliary values checked separately
+        let mut points: Vec<(String, Vec<(f32, f32)>)> = Vec::new();
+        for (i, series) in self.data.iter().enumerate() {
+            let xy = series.data.iter().map(|&(x, y)| (x.to_f32(), y.to_f32()));
+            points.push((series_label("series", i, series.name), xy.collect()));
+        }
+        for (i, series) in self.ohlc.iter().enumerate() {
+            let label = series_label("OHLC series", i, series.name);
+            if let Some(volume) = &series.volume
+                && volume.len() != series.data.len()
+            {
+                issues.push(Issue::MismatchedLength {
+                    series: label.clone(),
+                    field: "volume",
+                    expected: series.data.len(),
+                    found: volume.len(),
+                });
+            }
+            let xy = series.data.iter().flat_map(|&(t, open, high, low, close)| {
+                [open, high, low, close].map(|y| (t.to_f32(), y.to_f32()))
+            });
+            points.push((label, xy.collect()));
+        }
+        for (i, field) in self.vector_fields.iter().enumerate() {
+            let xy = field.data.iter().map(|&(x, y, ..)| (x.to_f32(), y.to_f32()));
+            points.push((series_label("vector field", i, field.name), xy.collect()));
+        }
 
-        let pixmap_size = tree
-            .size()
-            .to_int_size()
-            .scale_by(scale)
-            .ok_or("Invalid size")?;
-        let mut pixmap = skia::Pixmap::new(pixmap_size.width(), pixmap_size.height())
-            .ok_or("Failed to create pixmap")?;
+        let axes = [("x", self.x_scale, self.x_range), ("y", self.y_scale, self.y_range)];
+        for (index, (axis, scale, range)) in axes.into_iter().enumerate() {
+            let coordinate = |&point: &(f32, f32)| if index == 0 { point.0 } else { point.1 };
+            if let Range::Manual { min, max } = range
+                && min == max
+            {
+                issues.push(Issue::EmptyRange { axis });
+            }
+            for (label, series_points) in &points {
+                if series_points.is_empty() {
+                    continue;
+                }
+                if scale == Scale::Log {
+                    let count = series_points.iter().filter(|p| coordinate(p) <= 0.0).count();
+                    if count > 0 {
+                        issues.push(Issue::NonPositiveLogData {
+                            axis,
+                            series: label.clone(),
+                            count,
+                            total: series_points.len(),
+                        });
+                    }
+                }
+                if let Range::Manual { min, max } = range {
+                    let (min, max) = (min.to_f32(), max.to_f32());
+                    if series_points.iter().all(|p| coordinate(p) < min || coordinate(p) > max) {
+                        issues.push(Issue::DataOutsideRange { axis, series: label.clone() });
+                    }
+                }
+            }
+        }
 
-        let render_ts = skia::Transform::from_scale(scale, scale);
-        resvg::render(&tree, render_ts, &mut pixmap.as_mut());
+        if self.legend != Legend::None {
+            let names = self
+                .data
+                .iter()
+                .filter(|s| s.show_in_legend)
+                .map(|s| s.name)
+                .chain(self.ohlc.iter().filter(|s| s.show_in_legend).map(|s| s.name))
+                .chain(self.vector_fields.iter().filter(|f| f.show_in_legend).map(|f| f.name))
+                .chain(self.legend_entries.iter().map(|entry| entry.name))
+                .filter(|name| !name.is_empty());
+            let mut counts: Vec<(&str, usize)> = Vec::new();
+            for name in names {
+                match counts.iter_mut().find(|(seen, _)| *seen == name) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((name, 1)),
+                }
+            }
+            for (name, count) in counts.into_iter().filter(|&(_, count)| count > 1) {
+                issues.push(Issue::DuplicateLegendName { name: name.to_string(), count });
+            }
+        }
+        issues
+    }
 
-        pixmap.save_png(filename)?;
+    /// Rejects values and manual ranges that cannot be mapped onto the axes
+    fn check_data(&self) -> Result<(), Error> {
+        let axes = [
+            ("x", self.x_values(), self.x_scale, self.x_range),
+            ("y", self.y_values(), self.y_scale, self.y_range),
+        ];
+        for (axis, values, scale, range) in axes {
+            check_coordinates(axis, values.iter().map(|v| v.to_f64()))?;
+            let log = scale == Scale::Log;
+            match range {
+                Range::Auto => {
+                    if log && !values.is_empty() && values.iter().all(|v| v.to_f32() <= 0.0) {
+                        return Err(Error::InvalidData(format!(
+                            "log-scale {axis} axis has no positive values"
+                        )));
+                    }
+                }
+                Range::Manual { min, max } => check_range(axis, min.to_f32(), max.to_f32(), log)?,
+            }
+        }
+        for field in &self.vector_fields {
+            check_coordinates(
+                "vector field u and v",
+                field.data.iter().flat_map(|&(_, _, u, v)| [u.to_f64(), v.to_f64()]),
+            )?;
+        }
         Ok(())
     }
 
-    /// Converts the plot to PNG bytes, scaling the size of the image by the given scale factor.
-    ///
-    /// This method is only available when the "png" feature is enabled.
-    #[cfg(feature = "png")]
-    pub fn to_png_bytes(&self, scale: f32) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-        let document = self.plot()?;
-        let svg_string = document.to_string();
-
-        let mut opt = usvg::Options::default();
-        opt.fontdb_mut().load_system_fonts();
-
-        let tree = usvg::Tree::from_str(&svg_string, &opt)?;
-
-        let pixmap_size = tree
-            .size()
-            .to_int_size()
-            .scale_by(scale)
-            .ok_or("Invalid size")?;
-        let mut pixmap = skia::Pixmap::new(pixmap_size.width(), pixmap_size.height())
-            .ok_or("Failed to create pixmap")?;
-
-        let render_ts = skia::Transform::from_scale(scale, scale);
-        resvg::render(&tree, render_ts, &mut pixmap.as_mut());
-
-        Ok(pixmap.encode_png()?)
-    }
-
-    /// Converts the plot to an SVG document.
-    pub fn to_document(&self) -> Result<Document, std::io::Error> {
-        self.plot()
-    }
-
-    /// Generates an SVG document representing the plot.
-    fn plot(&self) -> Result<Document, std::io::Error> {
-        let (total_width, total_height) = self.dimensions;
-        let mut document = Document::new()
-            .set("width", total_width)
-            .set("height", total_height)
-            .set("viewBox", (0, 0, total_width, total_height));
-
-        // Background
-        let background = Rectangle::new()
-            .set("x", 0)
-            .set("y", 0)
-            .set("width", total_width)
-            .set("height", total_height)
-            .set("fill", "white");
-        document = document.add(background);
-
-        // Determine x_min, x_max, y_min, y_max based on Range
+    /// The settings shared with every other chart type
+    fn frame(&self) -> ChartFrame<'_> {
+        ChartFrame {
+            dimensions: self.dimensions,
+            title: self.title,
+            font: self.font,
+            background: &self.background,
+            margin: &self.margin,
+            legend: self.legend,
+            title_config: &self.title_config,
+            legend_config: &self.legend_config,
+            color_cycle: &self.color_cycle,
+            legend_entries: &self.legend_entries,
+        }
+    }
+
+    /// Fails with the first color that holds an unparsable string
+    fn check_colors(&self) -> Result<(), Error> {
+        let styles = [
+            &self.x_label_config.color,
+            &self.y_label_config.color,
+            &self.tick_config.label_color,
+            &self.tick_config.line_color,
+            &self.tick_config.minor_tick_color,
+            &self.axis_config.color,
+            &self.grid_config.color,
+            &self.grid_config.minor_color,
+        ];
+        let colors = styles
+            .into_iter()
+            .chain(&self.plot_background)
+            .chain(self.data.iter().flat_map(|s| s.color.iter().chain(&s.fill)))
+            .chain(self.ohlc.iter().flat_map(|s| [&s.up_color, &s.down_color]))
+            .chain(self.vector_fields.iter().map(|f| &f.color));
+        self.frame().check_colors(colors)
+    }
+
+    /// Runs every check that must pass before rendering, including validation in strict mode
+    fn check(&self) -> Result<(), Error> {
+        self.check_colors()?;
+        self.check_data()?;
+        if self.strict {
+            let issues = self.validate();
+            if !issues.is_empty() {
+                return Err(Error::Validation(issues));
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves the x and y axis ranges: manual ranges as given, automatic ones from the data,
+    /// widened to whole decades on log scales.
+    fn axis_ranges(&self) -> ((T, T), (T, T)) {
         let (actual_x_min, actual_x_max) = match self.x_range {
             Range::Auto => {
-                if self.data.is_empty() || self.data.iter().all(|s| s.data.is_empty()) {
+                let x_values = self.x_values();
+                if x_values.is_empty() {
                     (T::from_f32(0.0), T::from_f32(1.0))
                 } else {
                     let mut min_x = T::max_value();
                     let mut max_x = T::min_value();
-                    for series in &self.data {
-                        for (x, _) in &series.data {
-                            if *x < min_x {
-                                min_x = *x;
-                            }
-                            if *x > max_x {
-                                max_x = *x;
-                            }
+                    for x in x_values {
+                        if x < min_x {
+                            min_x = x;
+                        }
+                        if x > max_x {
+                            max_x = x;
                         }
                     }
                     if (max_x - min_x) < T::epsilon() {
@@ -195,22 +624,21 @@ impl<'a, T: PlotValue, const N: usize> Plot<'a, T, N> {
 
         let (actual_y_min, actual_y_max) = match self.y_range {
             Range::Auto => {
-                if self.data.is_empty() || self.data.iter().all(|s| s.data.is_empty()) {
+                let y_values = self.y_values();
+                if y_values.is_empty() {
                     (T::from_f32(0.0), T::from_f32(1.0))
                 } else {
                     let mut min_y = T::max_value();
                     let mut max_y = T::min_value();
-                    for series in &self.data {
-                        for (_, y) in &series.data {
-                            if *y < min_y {
-                                min_y = *y;
-                            }
-                            if *y > max_y {
-                                max_y = *y;
-                            }
+                    for y in y_values {
+                        if y < min_y {
+                            min_y = y;
+                        }
+                        if y > max_y {
+                            max_y = y;
                         }
                     }
-                    if (max_y - min_y) < T::epsilon() {
+                    let (min_y, max_y) = if (max_y - min_y) < T::epsilon() {
                         (min_y - T::from_f32(0.5), max_y + T::from_f32(0.5))
                     } else {
                         // For logarithmic Y scale, expand to nice power-of-10 bounds
@@ -221,151 +649,159 @@ impl<'a, T: PlotValue, const N: usize> Plot<'a, T, N> {
                         } else {
                             (min_y, max_y)
                         }
-                    }
+                    };
+                    (self.below_volume_band(min_y, max_y), max_y)
                 }
             }
             Range::Manual { min, max } => (min, max),
         };
+        ((actual_x_min, actual_x_max), (actual_y_min, actual_y_max))
+    }
 
-        // Calculate legend dimensions
-        let mut calculated_max_series_name_width = 0.0f32;
-        if self.legend != Legend::None && !self.data.is_empty() {
-            calculated_max_series_name_width = self
-                .data
-                .iter()
-                .map(|s| s.name.len() as f32 * self.legend_config.font_size * 0.6)
-                .fold(0.0f32, |a, b| a.max(b));
+    /// Lowers an automatic y minimum so that prices stay above the band at the bottom of the
+    /// plot area taken by OHLC volume bars
+    fn below_volume_band(&self, min_y: T, max_y: T) -> T {
+        let band = self
+            .ohlc
+            .iter()
+            .filter(|series| series.volume.is_some())
+            .map(|series| series.volume_height)
+            .fold(0.0f32, f32::max)
+            .min(0.9);
+        if band <= 0.0 {
+            return min_y;
         }
-
-        let legend_actual_box_width = if self.legend != Legend::None && !self.data.is_empty() {
-            self.legend_config.color_swatch_width
-                + self.legend_config.text_offset
-                + calculated_max_series_name_width
+        let (min, max) = (min_y.to_f32(), max_y.to_f32());
+        if self.y_scale == Scale::Log && min > 0.0 {
+            let (min_log, max_log) = (min.log10(), max.log10());
+            T::from_f32(10.0_f32.powf(max_log - (max_log - min_log) / (1.0 - band)))
         } else {
-            0.0
-        };
-        let legend_height = if self.legend != Legend::None && !self.data.is_empty() {
-            self.data.len() as f32 * self.legend_config.item_height
-                + self.legend_config.padding * 2.0
-        } else {
-            0.0
-        };
+            T::from_f32(max - (max - min) / (1.0 - band))
+        }
+    }
+
+    /// Lists the legend entries of every series shown in the legend, then the custom entries.
+    fn legend_items(&self, styles: &[SeriesStyle]) -> Vec<LegendEntry<'a>> {
+        self.data
+            .iter()
+            .zip(styles)
+            .filter(|(s, _)| s.show_in_legend)
+            .map(|(s, style)| LegendEntry::from_series(s, style))
+            .chain(self.ohlc.iter().filter(|s| s.show_in_legend).map(LegendEntry::from))
+            .chain(self.vector_fields.iter().filter(|f| f.show_in_legend).map(LegendEntry::from))
+            .chain(self.legend_entries.iter().cloned())
+            .collect()
+    }
 
-        // Adjust margins based on legend position
-        let current_effective_margin_left = self.margin.left;
-        let mut current_effective_margin_right = self.margin.right;
-        let current_effective_margin_top = self.margin.top;
-        let current_effective_margin_bottom = self.margin.bottom;
-
-        if self.legend != Legend::None && !self.data.is_empty() {
-            match self.legend {
-                Legend::TopRightOutside
-                | Legend::RightCenterOutside
-                | Legend::BottomRightOutside => {
-                    current_effective_margin_right +=
-                        legend_actual_box_width + self.legend_config.padding;
+    /// Draws the plot on any backend, such as a GUI canvas.
+    ///
+    /// The layout is complete before the first primitive is drawn, so nothing reaches the
+    /// backend when an error is returned.
+    pub fn render(&self, backend: &mut dyn Backend) -> Result<(), Error> {
+        self.check()?;
+        self.render_in(backend, self.frame(), self.layout)
+    }
+
+    /// Draws the checked plot into the canvas size and margins of `frame`.
+    fn render_in(
+        &self,
+        backend: &mut dyn Backend,
+        frame: ChartFrame<'_>,
+        layout: Layout,
+    ) -> Result<(), Error> {
+        let (total_width, total_height) = frame.dimensions;
+        let ((actual_x_min, actual_x_max), (actual_y_min, actual_y_max)) = self.axis_ranges();
+
+        // Calculate legend dimensions
+        let styles = series_styles(
+            &self.data,
+            &self.color_cycle,
+            &self.line_cycle,
+            &self.marker_cycle,
+        );
+        let legend_entries = self.legend_items(&styles);
+        let legend_layout = frame.legend_layout(&legend_entries);
+
+        // Room taken by a legend placed outside the plot area
+        let (legend_room_top, legend_room_bottom, legend_room_right) =
+            frame.legend_room(&legend_layout);
+
+        // Adjust margins based on layout and legend position
+        let margin = match layout {
+            Layout::Fixed => frame.margin.clone(),
+            Layout::Tight => {
+                // Tick labels depend on the plot area size, so refine once with the first estimate
+                let mut margin = frame.margin.clone();
+                for _ in 0..2 {
+                    let width =
+                        total_width as f32 - margin.left - margin.right - legend_room_right;
+                    let height = total_height as f32
+                        - margin.top
+                        - margin.bottom
+                        - legend_room_top
+                        - legend_room_bottom;
+                    let (x_ticks, y_ticks) = self.ticks(
+                        (actual_x_min, actual_x_max),
+                        (actual_y_min, actual_y_max),
+                        width.max(1.0),
+                        height.max(1.0),
+                    );
+                    margin = self.tight_margin(
+                        &x_ticks,
+                        &y_ticks,
+                        (actual_x_min, actual_x_max),
+                        width.max(1.0),
+                    );
                 }
-                _ => {}
+                margin
             }
-        }
-
-        // Calculate plot area dimensions
-        let plot_area_x_start = current_effective_margin_left;
-        let plot_area_y_start = current_effective_margin_top;
-        let plot_area_width =
-            total_width as f32 - current_effective_margin_left - current_effective_margin_right;
-        let plot_area_height =
-            total_height as f32 - current_effective_margin_top - current_effective_margin_bottom;
-
-        if plot_area_width <= 0.0 || plot_area_height <= 0.0 {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                format!(
-                    "Plot area is too small (width: {}, height: {}). Check dimensions and margins.",
-                    plot_area_width, plot_area_height
-                ),
-            ));
-        }
+        };
+        let layout = frame.layout(margin, legend_layout)?;
+        let margin = &layout.margin;
+        let plot_area = layout.plot_area;
+        let Rect {
+            x: plot_area_x_start,
+            y: plot_area_y_start,
+            width: plot_area_width,
+            height: plot_area_height,
+            ..
+        } = plot_area;
 
         // Helper closures to map data coordinates to screen coordinates
+        let x_bounds = (actual_x_min, actual_x_max);
+        let y_bounds = (actual_y_min, actual_y_max);
         let map_x = |data_x: T| -> f32 {
-            if (actual_x_max - actual_x_min) < T::epsilon() {
-                plot_area_x_start + plot_area_width / 2.0
-            } else {
-                let data_x_f32 = data_x.to_f32();
-                let actual_x_min_f32 = actual_x_min.to_f32();
-                let actual_x_max_f32 = actual_x_max.to_f32();
-                
-                // Apply logarithmic transformation if needed
-                if self.x_scale == Scale::Log {
-                    // Ensure positive values for logarithmic scale
-                    let safe_data_x = if data_x_f32 > 0.0 { data_x_f32 } else { 0.001 };
-                    let safe_min = if actual_x_min_f32 > 0.0 { actual_x_min_f32 } else { 1.0 };
-                    let safe_max = if actual_x_max_f32 > 0.0 { actual_x_max_f32 } else { 10.0 };
-                    
-                    let log_data_x = safe_data_x.log10();
-                    let log_min = safe_min.log10();
-                    let log_max = safe_max.log10();
-                    
-                    if (log_max - log_min).abs() < f32::EPSILON {
-                        plot_area_x_start + plot_area_width / 2.0
-                    } else {
-                        plot_area_x_start + ((log_data_x - log_min) / (log_max - log_min) * plot_area_width)
-                    }
-                } else {
-                    plot_area_x_start
-                        + ((data_x_f32 - actual_x_min_f32) / (actual_x_max_f32 - actual_x_min_f32)
-                            * plot_area_width)
-                }
-            }
+            plot_area_x_start + axis_fraction(data_x, x_bounds, self.x_scale) * plot_area_width
         };
         let map_y = |data_y: T| -> f32 {
-            if (actual_y_max - actual_y_min) < T::epsilon() {
-                plot_area_y_start + plot_area_height / 2.0
-            } else {
-                let data_y_f32 = data_y.to_f32();
-                let actual_y_min_f32 = actual_y_min.to_f32();
-                let actual_y_max_f32 = actual_y_max.to_f32();
-                
-                // Apply logarithmic transformation if needed
-                if self.y_scale == Scale::Log {
-                    // Ensure positive values for logarithmic scale
-                    let safe_data_y = if data_y_f32 > 0.0 { data_y_f32 } else { 0.001 };
-                    let safe_min = if actual_y_min_f32 > 0.0 { actual_y_min_f32 } else { 1.0 };
-                    let safe_max = if actual_y_max_f32 > 0.0 { actual_y_max_f32 } else { 10.0 };
-                    
-                    let log_data_y = safe_data_y.log10();
-                    let log_min = safe_min.log10();
-                    let log_max = safe_max.log10();
-                    
-                    if (log_max - log_min).abs() < f32::EPSILON {
-                        plot_area_y_start + plot_area_height / 2.0
-                    } else {
-                        plot_area_y_start + plot_area_height
-                            - ((log_data_y - log_min) / (log_max - log_min) * plot_area_height)
-                    }
-                } else {
-                    plot_area_y_start + plot_area_height
-                        - ((data_y_f32 - actual_y_min_f32) / (actual_y_max_f32 - actual_y_min_f32)
-                            * plot_area_height)
-                }
-            }
+            plot_area_y_start + plot_area_height
+                - axis_fraction(data_y, y_bounds, self.y_scale) * plot_area_height
         };
 
-        // --- Draw Title ---
-        document = draw_title(
-            document,
-            self.title,
-            self.font,
-            &self.title_config,
-            plot_area_x_start,
+        // --- Tick Values ---
+        let (x_ticks, y_ticks) = self.ticks(
+            (actual_x_min, actual_x_max),
+            (actual_y_min, actual_y_max),
             plot_area_width,
-            current_effective_margin_top,
+            plot_area_height,
         );
+        let y_tick_label_extent = self.y_tick_label_extent(&y_ticks);
+
+        // --- Background ---
+        frame.draw_background(backend);
+
+        // --- Plot Area Background ---
+        if let Some(plot_background) = &self.plot_background {
+            backend.set_role(Role::PlotArea);
+            backend.rect(&plot_area, &ShapeStyle::filled(plot_background.clone()));
+        }
+
+        // --- Draw Title ---
+        frame.draw_title(backend, &layout);
 
         // --- Draw X-axis Label ---
-        document = draw_x_label(
-            document,
+        draw_x_label(
+            backend,
             self.x_label,
             self.font,
             &self.x_label_config,
@@ -373,23 +809,24 @@ impl<'a, T: PlotValue, const N: usize> Plot<'a, T, N> {
             plot_area_width,
             plot_area_y_start,
             plot_area_height,
-            current_effective_margin_bottom,
+            margin.bottom,
         );
 
         // --- Draw Y-axis Label ---
-        document = draw_y_label(
-            document,
+        draw_y_label(
+            backend,
             self.y_label,
             self.font,
             &self.y_label_config,
-            current_effective_margin_left,
+            margin.left,
+            y_tick_label_extent,
             plot_area_y_start,
             plot_area_height,
         );
 
         // --- Draw Axis Lines ---
-        document = draw_axis_lines(
-            document,
+        draw_axis_lines(
+            backend,
             self.axis,
             &self.axis_config,
             plot_area_x_start,
@@ -399,113 +836,8 @@ impl<'a, T: PlotValue, const N: usize> Plot<'a, T, N> {
         );
 
         // --- Tick Marks, Grid Lines, and Tick Labels ---
-        let num_x_ticks = (plot_area_width / self.tick_config.density_x).max(2.0) as usize;
-        let num_y_ticks = (plot_area_height / self.tick_config.density_y).max(2.0) as usize;
-
-        let calculate_linear_ticks = |min_val: f32, max_val: f32, max_ticks: usize| -> Vec<f32> {
-            if (max_val - min_val).abs() < f32::EPSILON {
-                return vec![min_val];
-            }
-            let range = max_val - min_val;
-            let rough_step = range / (max_ticks.saturating_sub(1) as f32).max(1.0);
-            if rough_step == 0.0 {
-                return vec![min_val];
-            }
-            let exponent = rough_step.log10().floor();
-            let fraction = rough_step / 10f32.powf(exponent);
-            let nice_fraction = if fraction < 1.5 {
-                1.0
-            } else if fraction < 3.5 {
-                2.0
-            } else if fraction < 7.5 {
-                5.0
-            } else {
-                10.0
-            };
-            let step = nice_fraction * 10f32.powf(exponent);
-            if step == 0.0 {
-                return vec![min_val, max_val].into_iter().collect();
-            }
-
-            let start_tick = (min_val / step).floor() * step;
-            let mut ticks = Vec::new();
-            let mut current_tick = start_tick;
-
-            while current_tick <= max_val + step * 0.5 {
-                if current_tick >= min_val - step * 0.1 && current_tick <= max_val + step * 0.1 {
-                    ticks.push(current_tick);
-                }
-                current_tick += step;
-                if ticks.len() > max_ticks * 2 {
-                    break;
-                }
-            }
-
-            if ticks.is_empty() {
-                if min_val == max_val {
-                    ticks.push(min_val);
-                } else {
-                    ticks.extend_from_slice(&[min_val, max_val]);
-                }
-            } else if ticks.len() == 1 && min_val != max_val {
-                ticks.push(max_val);
-            }
-            ticks
-        };
-
-        let calculate_log_ticks = |min_val: f32, max_val: f32| -> Vec<f32> {
-            // Handle cases where min_val is 0 or negative by using a small positive value
-            let safe_min_val = if min_val <= 0.0 {
-                if max_val > 1.0 {
-                    1.0 // Start from 1 if max is reasonable
-                } else {
-                    0.001 // Use a small positive value
-                }
-            } else {
-                min_val
-            };
-            
-            let safe_max_val = if max_val <= 0.0 {
-                safe_min_val * 1000.0 // Ensure we have a reasonable range
-            } else {
-                max_val
-            };
-
-            let log_min = safe_min_val.log10().floor();
-            let log_max = safe_max_val.log10().ceil();
-            let mut ticks = Vec::new();
-
-            // Generate only major ticks (powers of 10)
-            for exp in (log_min as i32)..=(log_max as i32) {
-                let tick_value = 10.0_f32.powi(exp);
-                if tick_value >= safe_min_val && tick_value <= safe_max_val {
-                    ticks.push(tick_value);
-                }
-            }
-
-            // Ensure we have at least some ticks
-            if ticks.is_empty() {
-                ticks.push(safe_min_val);
-                ticks.push(safe_max_val);
-            }
-
-            ticks
-        };
-
-        let x_ticks = if self.x_scale == Scale::Log {
-            calculate_log_ticks(actual_x_min.to_f32(), actual_x_max.to_f32())
-        } else {
-            calculate_linear_ticks(actual_x_min.to_f32(), actual_x_max.to_f32(), num_x_ticks)
-        };
-
-        let y_ticks = if self.y_scale == Scale::Log {
-            calculate_log_ticks(actual_y_min.to_f32(), actual_y_max.to_f32())
-        } else {
-            calculate_linear_ticks(actual_y_min.to_f32(), actual_y_max.to_f32(), num_y_ticks)
-        };
-
-        document = draw_ticks_and_grids(
-            document,
+        draw_ticks_and_grids(
+            backend,
             self.axis,
             self.tick,
             self.grid,
@@ -525,107 +857,327 @@ impl<'a, T: PlotValue, const N: usize> Plot<'a, T, N> {
             |y_f32| map_y(T::from_f32(y_f32)),
         );
 
-        // --- Clipping Path for Plot Area ---
-        let clip_path_id = "plotAreaClip";
-        let clip_rect = Rectangle::new()
-            .set("x", plot_area_x_start)
-            .set("y", plot_area_y_start)
-            .set("width", plot_area_width)
-            .set("height", plot_area_height);
-        let clip_path = ClipPath::new().set("id", clip_path_id).add(clip_rect);
-        let mut defs = Definitions::new();
-        defs = defs.add(clip_path);
-        document = document.add(defs);
-
-        // --- Data Series Drawing ---
-        let data_group = draw_data_series(&self.data[..], map_x, map_y);
-        document = document.add(data_group);
+        // --- Data Drawing, Clipped to the Plot Area ---
+        backend.begin_clip("plotAreaClip", &Clip::Rect(plot_area));
+        if !self.ohlc.is_empty() {
+            draw_ohlc_series(
+                backend,
+                &self.ohlc,
+                &map_x,
+                &map_y,
+                plot_area_y_start,
+                plot_area_height,
+            );
+        }
+        if !self.vector_fields.is_empty() {
+            // Arrows are placed in f32 so that integer data keeps fractional offsets
+            let (x_bounds, y_bounds) = (
+                (x_bounds.0.to_f32(), x_bounds.1.to_f32()),
+                (y_bounds.0.to_f32(), y_bounds.1.to_f32()),
+            );
+            let map_x = |x: f32| {
+                plot_area_x_start + axis_fraction(x, x_bounds, self.x_scale) * plot_area_width
+            };
+            let map_y = |y: f32| {
+                plot_area_y_start + plot_area_height
+                    - axis_fraction(y, y_bounds, self.y_scale) * plot_area_height
+            };
+            let pixels_per_unit = |x: f32, y: f32| {
+                (
+                    axis_density(x, x_bounds, self.x_scale) * plot_area_width,
+                    axis_density(y, y_bounds, self.y_scale) * plot_area_height,
+                )
+            };
+            draw_vector_fields(backend, &self.vector_fields, &map_x, &map_y, &pixels_per_unit);
+        }
+        draw_data_series(backend, &self.data[..], &styles, map_x, map_y);
+        backend.end_clip();
 
         // --- Legend Drawing ---
-        if self.legend != Legend::None && !self.data.is_empty() {
-            let legend_x_base;
-            let legend_y_base;
-            match self.legend {
-                Legend::TopRightInside => {
-                    legend_x_base = plot_area_x_start + plot_area_width
-                        - legend_actual_box_width
-                        - self.legend_config.padding;
-                    legend_y_base = plot_area_y_start + self.legend_config.padding;
-                }
-                Legend::TopRightOutside => {
-                    legend_x_base = total_width as f32 - current_effective_margin_right
-                        + self.legend_config.padding;
-                    legend_y_base = plot_area_y_start + self.legend_config.padding;
-                }
-                Legend::BottomRightInside => {
-                    legend_x_base = plot_area_x_start + plot_area_width
-                        - legend_actual_box_width
-                        - self.legend_config.padding;
-                    legend_y_base = plot_area_y_start + plot_area_height
-                        - legend_height
-                        - self.legend_config.padding;
-                }
-                Legend::BottomRightOutside => {
-                    legend_x_base = total_width as f32 - current_effective_margin_right
-                        + self.legend_config.padding;
-                    legend_y_base = plot_area_y_start + plot_area_height
-                        - legend_height
-                        - self.legend_config.padding;
-                }
-                Legend::TopLeftInside => {
-                    legend_x_base = plot_area_x_start + self.legend_config.padding;
-                    legend_y_base = plot_area_y_start + self.legend_config.padding;
-                }
-                Legend::BottomLeftInside => {
-                    legend_x_base = plot_area_x_start + self.legend_config.padding;
-                    legend_y_base = plot_area_y_start + plot_area_height
-                        - legend_height
-                        - self.legend_config.padding;
-                }
-                Legend::RightCenterInside => {
-                    legend_x_base = plot_area_x_start + plot_area_width
-                        - legend_actual_box_width
-                        - self.legend_config.padding;
-                    legend_y_base = plot_area_y_start + (plot_area_height - legend_height) / 2.0;
-                }
-                Legend::RightCenterOutside => {
-                    legend_x_base = total_width as f32 - current_effective_margin_right
-                        + self.legend_config.padding;
-                    legend_y_base = plot_area_y_start + (plot_area_height - legend_height) / 2.0;
-                }
-                Legend::LeftCenterInside => {
-                    legend_x_base = plot_area_x_start + self.legend_config.padding;
-                    legend_y_base = plot_area_y_start + (plot_area_height - legend_height) / 2.0;
-                }
-                Legend::TopCenter => {
-                    legend_x_base =
-                        plot_area_x_start + (plot_area_width - legend_actual_box_width) / 2.0;
-                    legend_y_base = plot_area_y_start + self.legend_config.padding;
-                }
-                Legend::BottomCenter => {
-                    legend_x_base =
-                        plot_area_x_start + (plot_area_width - legend_actual_box_width) / 2.0;
-                    legend_y_base = plot_area_y_start + plot_area_height
-                        - legend_height
-                        - self.legend_config.padding;
-                }
-                Legend::None => {
-                    legend_x_base = 0.0;
-                    legend_y_base = 0.0;
-                }
+        frame.draw_legend(backend, &legend_entries, &layout, || {
+            self.screen_polylines(&map_x, &map_y)
+        });
+        Ok(())
+    }
+}
+
+impl<'a, T: PlotValue, const N: usize> Chart for Plot<'a, T, N> {
+    fn render(&self, backend: &mut dyn Backend) -> Result<(), Error> {
+        Plot::render(self, backend)
+    }
+
+    fn svg_format(&self) -> SvgFormat {
+        self.svg_format
+    }
+}
+
+/// Position of `value` along an axis spanning `min` to `max`, from 0.0 at `min` to 1.0 at `max`.
+///
+/// Log scales clamp non-positive values to 0.001; an empty span maps everything to the middle.
+pub(crate) fn axis_fraction<T: PlotValue>(value: T, (min, max): (T, T), scale: Scale) -> f32 {
+    if (max - min) < T::epsilon() {
+        return 0.5;
+    }
+    let (value, min, max) = (value.to_f32(), min.to_f32(), max.to_f32());
+    if scale == Scale::Log {
+        // Ensure positive values for logarithmic scale
+        let safe_value = if value > 0.0 { value } else { 0.001 };
+        let safe_min = if min > 0.0 { min } else { 1.0 };
+        let safe_max = if max > 0.0 { max } else { 10.0 };
+
+        let log_value = safe_value.log10();
+        let log_min = safe_min.log10();
+        let log_max = safe_max.log10();
+
+        if (log_max - log_min).abs() < f32::EPSILON {
+            0.5
+        } else {
+            (log_value - log_min) / (log_max - log_min)
+        }
+    } else {
+        (value - min) / (max - min)
+    }
+}
+
+/// Rate at which `axis_fraction` changes per data unit at `value`, the derivative of the
+/// axis mapping
+pub(crate) fn axis_density(value: f32, (min, max): (f32, f32), scale: Scale) -> f32 {
+    let span = max - min;
+    if span < f32::EPSILON {
+        return 0.0;
+    }
+    if scale == Scale::Log {
+        let safe_min = if min > 0.0 { min } else { 1.0 };
+        let safe_max = if max > 0.0 { max } else { 10.0 };
+        let log_span = safe_max.log10() - safe_min.log10();
+        if value <= 0.0 || log_span.abs() < f32::EPSILON {
+            0.0
+        } else {
+            1.0 / (value * std::f32::consts::LN_10 * log_span)
+        }
+    } else {
+        1.0 / span
+    }
+}
+
+/// Places the labels of the major ticks within an axis range for terminal output
+fn terminal_ticks<T: PlotValue>(ticks: &[f32], (min, max): (T, T), scale: Scale) -> Vec<(f32, String)> {
+    let scale_factor = 10.0_f32.powi(scale_exponent(ticks, scale));
+    let bounds = (min.to_f32(), max.to_f32());
+    major_ticks(ticks, scale)
+        .into_iter()
+        .map(|tick| (axis_fraction(tick, bounds, scale), tick))
+        .filter(|(fraction, _)| (-0.001..=1.001).contains(fraction))
+        .map(|(fraction, tick)| (fraction.clamp(0.0, 1.0), tick_label(tick, scale, scale_factor)))
+        .collect()
+}
+
+/// Appends the power of ten factored out of the tick labels to an axis label
+fn terminal_axis_label(label: &str, ticks: &[f32], scale: Scale) -> String {
+    match scale_exponent(ticks, scale) {
+        0 => label.to_string(),
+        exponent if label.is_empty() => format!("×10^{exponent}"),
+        exponent => format!("{label} (×10^{exponent})"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::Severity;
+
+    fn series(name: &str, data: Vec<(f32, f32)>) -> Series<'_> {
+        Series::builder().name(name).data(data).build()
+    }
+
+    #[test]
+    fn validate_reports_log_data_by_severity() {
+        let plot = Plot::builder()
+            .y_scale(Scale::Log)
+            .data([
+                series("some", vec![(0.0, -1.0), (1.0, 10.0)]),
+                series("none", vec![(0.0, 0.0), (1.0, -2.0)]),
+            ])
+            .build();
+        let issues = plot.validate();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(
+            issues[0],
+            Issue::NonPositiveLogData {
+                axis: "y",
+                series: "\"some\"".to_string(),
+                count: 1,
+                total: 2,
             }
+        );
+        assert_eq!(issues[0].severity(), Severity::Warning);
+        assert_eq!(issues[1].severity(), Severity::Error);
+    }
 
-            document = draw_legend(
-                document,
-                &self.data[..],
-                self.font,
-                &self.legend_config,
-                legend_x_base,
-                legend_y_base,
-                legend_actual_box_width,
-                legend_height,
-            );
+    #[test]
+    fn validate_reports_ranges_lengths_and_legend_names() {
+        let plot = Plot::builder()
+            .x_range(Range::Manual { min: 5.0, max: 5.0 })
+            .y_range(Range::Manual { min: 100.0, max: 200.0 })
+            .legend(Legend::TopRightInside)
+            .data([series("a", vec![(5.0, 1.0)]), series("a", vec![(5.0, 150.0)])])
+            .ohlc(vec![
+                OhlcSeries::builder()
+                    .data(vec![(5.0, 150.0, 160.0, 140.0, 155.0)])
+                    .volume(vec![1.0, 2.0])
+                    .build(),
+            ])
+            .build();
+        let issues = plot.validate();
+        assert!(issues.contains(&Issue::EmptyRange { axis: "x" }));
+        assert!(issues.contains(&Issue::DataOutsideRange {
+            axis: "y",
+            series: "\"a\"".to_string(),
+        }));
+        assert!(issues.contains(&Issue::MismatchedLength {
+            series: "OHLC series 1".to_string(),
+            field: "volume",
+            expected: 1,
+            found: 2,
+        }));
+        assert!(issues.contains(&Issue::DuplicateLegendName { name: "a".to_string(), count: 2 }));
+        assert_eq!(issues.len(), 4);
+    }
+
+    #[test]
+    fn validate_passes_clean_plot() {
+        let plot = Plot::builder().data([series("a", vec![(0.0, 1.0), (1.0, 2.0)])]).build();
+        assert!(plot.validate().is_empty());
+        assert!(plot.check().is_ok());
+    }
+
+    #[test]
+    fn check_rejects_unplottable_data() {
+        let nan = Plot::builder().data([series("", vec![(0.0, f32::NAN)])]).build();
+        assert!(matches!(nan.check(), Err(Error::InvalidData(_))));
+
+        let log = Plot::builder().y_scale(Scale::Log).data([series("", vec![(0.0, -1.0)])]).build();
+        assert!(matches!(log.check(), Err(Error::InvalidData(_))));
+
+        let range = Plot::builder()
+            .x_range(Range::Manual { min: 2.0, max: 1.0 })
+            .data([series("", vec![(0.0, 1.0)])])
+            .build();
+        assert!(matches!(range.check(), Err(Error::InvalidRange(_))));
+    }
+
+    #[test]
+    fn check_reports_f64_values_beyond_the_f32_range() {
+        let check = |y: f64| {
+            let data: Series<f64> = Series::builder().data(vec![(0.0, 1.0), (1.0, y)]).build();
+            Plot::builder().data([data]).build().check()
+        };
+        assert!(check(1e38).is_ok());
+        match check(1e300) {
+            Err(Error::InvalidData(message)) => {
+                assert!(message.contains("found 1e300"), "{message}");
+                assert!(!message.contains("finite"), "{message}");
+            }
+            other => panic!("expected InvalidData, got {other:?}"),
+        }
+        match check(f64::INFINITY) {
+            Err(Error::InvalidData(message)) => assert!(message.contains("finite"), "{message}"),
+            other => panic!("expected InvalidData, got {other:?}"),
         }
-        Ok(document)
+    }
+
+    #[test]
+    fn check_fails_on_warnings_only_in_strict_mode() {
+        let build = |strict| {
+            Plot::builder()
+                .strict(strict)
+                .legend(Legend::TopRightInside)
+                .data([series("a", vec![(0.0, 1.0)]), series("a", vec![(1.0, 2.0)])])
+                .build()
+        };
+        assert!(build(false).check().is_ok());
+        match build(true).check() {
+            Err(Error::Validation(issues)) => {
+                assert_eq!(issues, vec![Issue::DuplicateLegendName { name: "a".to_string(), count: 2 }]);
+            }
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn terminal_output_ignores_pixel_margins_and_size() {
+        let build = |dimensions, margin| {
+            Plot::builder()
+                .dimensions(dimensions)
+                .margin(margin)
+                .legend(Legend::TopOutside)
+                .data([series("line", vec![(0.0, 0.0), (1.0, 1.0)])])
+                .build()
+        };
+        let config = TerminalConfig {
+            color: false,
+            ..TerminalConfig::default()
+        };
+        let crowded = build((400, 300), Margin::new(140.0, 140.0, 190.0, 190.0));
+        assert!(matches!(crowded.to_svg_string(), Err(Error::PlotAreaTooSmall { .. })));
+        let expected = build((800, 600), Margin::default()).to_terminal(&config).unwrap();
+        assert_eq!(crowded.to_terminal(&config).unwrap(), expected);
+    }
+
+    /// Margins of the tight layout of `plot` for a 640 by 440 pixel plot area
+    fn tight_margin(plot: &Plot) -> Margin {
+        let (x_bounds, y_bounds) = plot.axis_ranges();
+        let (x_ticks, y_ticks) = plot.ticks(x_bounds, y_bounds, 640.0, 440.0);
+        plot.tight_margin(&x_ticks, &y_ticks, x_bounds, 640.0)
+    }
+
+    #[test]
+    fn tight_layout_grows_margins_to_fit_tick_labels() {
+        let build = |y_max: f32, margin: Margin| {
+            // Plain labels such as "120000", without a shared exponent
+            Plot::builder()
+                .layout(Layout::Tight)
+                .margin(margin)
+                .y_scale(Scale::None)
+                .data([series("", vec![(0.0, 0.0), (1.0, y_max)])])
+                .build()
+        };
+        let thin = Margin::new(1.0, 1.0, 1.0, 1.0);
+        let short = build(1.0, thin.clone());
+        let long = build(123456.0, thin.clone());
+        let (short_margin, long_margin) = (tight_margin(&short), tight_margin(&long));
+
+        // The left margin holds the widest y tick label
+        let (_, y_bounds) = long.axis_ranges();
+        let (_, y_ticks) = long.ticks((0.0, 1.0), y_bounds, 640.0, 440.0);
+        let widest = long.tick_label_widths(&y_ticks, long.y_scale).into_iter().map(|(_, w)| w);
+        assert!(long_margin.left >= widest.fold(0.0, f32::max));
+        assert!(long_margin.left > short_margin.left + 10.0, "{long_margin:?} {short_margin:?}");
+        assert_eq!(long_margin.bottom, short_margin.bottom);
+
+        // Rendering uses the grown margins, where the fixed layout would keep the thin ones
+        let render = |plot: &Plot| plot.to_svg_string().unwrap();
+        let fixed = Plot { layout: Layout::Fixed, ..build(123456.0, thin) };
+        assert!(render(&long) != render(&fixed));
+    }
+
+    #[test]
+    fn tight_layout_keeps_configured_margins_as_minimums() {
+        let margin = Margin::new(90.0, 80.0, 150.0, 70.0);
+        let plot = Plot::builder()
+            .layout(Layout::Tight)
+            .margin(margin.clone())
+            .title("Title")
+            .x_label("x")
+            .y_label("y")
+            .data([series("", vec![(0.0, 0.0), (1.0, 10.0)])])
+            .build();
+        assert_eq!(tight_margin(&plot), margin);
+
+        // Only the sides that need more room grow
+        let wide = Plot { margin: Margin::new(90.0, 1.0, 150.0, 70.0), ..plot };
+        let grown = tight_margin(&wide);
+        assert!(grown.bottom > 1.0);
+        assert_eq!((grown.top, grown.left, grown.right), (90.0, 150.0, 70.0));
     }
 }
@@ -0,0 +1,387 @@
+use crate::{
+    backend::{Backend, Clip, Rect},
+    color::Color,
+    error::{Error, check_coordinates, check_range},
+    traits::{Chart, PlotValue},
+    draw::{ChartFrame, calculate_linear_ticks, draw_polar_grid, draw_polar_series},
+    elements::*,
+    legend_entry::LegendEntry,
+    series::{Series, series_styles},
+    style::*,
+};
+use bon::Builder;
+use std::f32::consts::PI;
+
+/// A plot in polar coordinates where each series point is `(theta, r)` with `theta` in radians.
+#[derive(Builder)]
+pub struct PolarPlot<'a, T: PlotValue = f32, const N: usize = 1> {
+    // --- Plot Settings ---
+    #[builder(default = (800, 600))]
+    pub dimensions: (i32, i32),
+    #[builder(default = "")]
+    pub title: &'a str,
+    #[builder(default = Range::Auto)]
+    pub r_range: Range<T>,
+    #[builder(default = Legend::None)]
+    pub legend: Legend,
+    #[builder(default = Grid::Solid)]
+    pub grid: Grid,
+    /// Screen direction in which `theta = 0` points
+    #[builder(default = AngleZero::East)]
+    pub angle_zero: AngleZero,
+    /// Direction in which increasing `theta` rotates
+    #[builder(default = Direction::CounterClockwise)]
+    pub direction: Direction,
+    #[builder(default = AngleUnit::Degrees)]
+    pub angle_unit: AngleUnit,
+    /// Number of angular gridlines around the full circle
+    #[builder(default = 12)]
+    pub angular_divisions: usize,
+    #[builder(default = "Times New Roman")]
+    pub font: &'a str,
+    /// Colors given in turn to series without an explicit color; series are black when empty.
+    /// Accepts a [`Palette`] or any `Vec<Color>`
+    #[builder(default = Palette::Category10.colors(), into)]
+    pub color_cycle: Vec<Color>,
+    /// Line styles given in turn to solid-line series, e.g. for grayscale output
+    #[builder(default)]
+    pub line_cycle: Vec<Line>,
+    /// Markers given in turn to series without markers
+    #[builder(default)]
+    pub marker_cycle: Vec<Marker>,
+    /// Text layout of SVG output: indented or on a single line
+    #[builder(default)]
+    pub svg_format: SvgFormat,
+
+    // --- Style Configurations ---
+    /// Fill of the whole image
+    #[builder(default = Color::White, into)]
+    pub background: Color,
+    #[builder(default = Margin::default())]
+    pub margin: Margin,
+    #[builder(default = TitleConfig::default())]
+    pub title_config: TitleConfig,
+    #[builder(default = TickConfig::default())]
+    pub tick_config: TickConfig,
+    #[builder(default = LegendConfig::default())]
+    pub legend_config: LegendConfig,
+    #[builder(default = AxisConfig::default())]
+    pub axis_config: AxisConfig,
+    #[builder(default = GridConfig::default())]
+    pub grid_config: GridConfig,
+
+    // --- Data ---
+    pub data: [Series<'a, T>; N],
+    /// Legend-only entries listed after the chart's own
+    #[builder(default)]
+    pub legend_entries: Vec<LegendEntry<'a>>,
+}
+
+impl<'a, T: PlotValue, const N: usize> PolarPlot<'a, T, N> {
+    /// Rejects values and manual ranges that cannot be mapped onto the chart
+    fn check_data(&self) -> Result<(), Error> {
+        let points = self.data.iter().flat_map(|s| &s.data);
+        check_coordinates("theta", points.clone().map(|(theta, _)| theta.to_f64()))?;
+        check_coordinates("r", points.map(|(_, r)| r.to_f64()))?;
+        match self.r_range {
+            Range::Auto => Ok(()),
+            Range::Manual { min, max } => check_range("r", min.to_f32(), max.to_f32(), false),
+        }
+    }
+
+    /// The settings shared with every other chart type
+    fn frame(&self) -> ChartFrame<'_> {
+        ChartFrame {
+            dimensions: self.dimensions,
+            title: self.title,
+            font: self.font,
+            background: &self.background,
+            margin: &self.margin,
+            legend: self.legend,
+            title_config: &self.title_config,
+            legend_config: &self.legend_config,
+            color_cycle: &self.color_cycle,
+            legend_entries: &self.legend_entries,
+        }
+    }
+
+    /// Fails with the first color that holds an unparsable string
+    fn check_colors(&self) -> Result<(), Error> {
+        let styles = [
+            &self.tick_config.label_color,
+            &self.tick_config.line_color,
+            &self.tick_config.minor_tick_color,
+            &self.axis_config.color,
+            &self.grid_config.color,
+            &self.grid_config.minor_color,
+        ];
+        let series = self.data.iter().flat_map(|s| s.color.iter().chain(&s.fill));
+        self.frame().check_colors(styles.into_iter().chain(series))
+    }
+
+    /// Draws the plot on any backend, such as a GUI canvas.
+    ///
+    /// The layout is complete before the first primitive is drawn, so nothing reaches the
+    /// backend when an error is returned.
+    pub fn render(&self, backend: &mut dyn Backend) -> Result<(), Error> {
+        self.check_colors()?;
+        self.check_data()?;
+
+        // Calculate legend dimensions
+        let styles = series_styles(
+            &self.data,
+            &self.color_cycle,
+            &self.line_cycle,
+            &self.marker_cycle,
+        );
+        let legend_entries: Vec<LegendEntry> = self
+            .data
+            .iter()
+            .zip(&styles)
+            .filter(|(s, _)| s.show_in_legend)
+            .map(|(s, style)| LegendEntry::from_series(s, style))
+            .chain(self.legend_entries.iter().cloned())
+            .collect();
+        let frame = self.frame();
+        let layout = frame.layout(self.margin.clone(), frame.legend_layout(&legend_entries))?;
+        let Rect {
+            x: plot_area_x_start,
+            y: plot_area_y_start,
+            width: plot_area_width,
+            height: plot_area_height,
+            ..
+        } = layout.plot_area;
+
+        // Leave room around the circle for the angular labels
+        let label_room = self.tick_config.text_padding + self.tick_config.font_size * 2.0;
+        let radius = plot_area_width.min(plot_area_height) / 2.0 - label_room;
+
+        if plot_area_width <= 0.0 || plot_area_height <= 0.0 || radius <= 0.0 {
+            return Err(Error::PlotAreaTooSmall {
+                width: plot_area_width,
+                height: plot_area_height,
+            });
+        }
+
+        let cx = plot_area_x_start + plot_area_width / 2.0;
+        let cy = plot_area_y_start + plot_area_height / 2.0;
+
+        // Determine the radial range, starting from zero unless the data is negative
+        let num_r_ticks = (radius / self.tick_config.density_y).max(2.0) as usize;
+        let (r_min, r_max) = match self.r_range {
+            Range::Auto => {
+                let mut min_r = 0.0f32;
+                let mut max_r = f32::MIN;
+                for series in &self.data {
+                    for (_, r) in &series.data {
+                        min_r = min_r.min(r.to_f32());
+                        max_r = max_r.max(r.to_f32());
+                    }
+                }
+                if max_r <= min_r {
+                    (min_r, min_r + 1.0)
+                } else {
+                    let ticks = calculate_linear_ticks(min_r, max_r, num_r_ticks);
+                    let nice_max = ticks.last().copied().unwrap_or(max_r).max(max_r);
+                    (min_r, nice_max)
+                }
+            }
+            Range::Manual { min, max } => (min.to_f32(), max.to_f32()),
+        };
+        let radial_ticks = calculate_linear_ticks(r_min, r_max, num_r_ticks);
+
+        let zero_offset = match self.angle_zero {
+            AngleZero::East => 0.0,
+            AngleZero::North => PI / 2.0,
+            AngleZero::West => PI,
+            AngleZero::South => 3.0 * PI / 2.0,
+        };
+        let sign = match self.direction {
+            Direction::CounterClockwise => 1.0,
+            Direction::Clockwise => -1.0,
+        };
+
+        // Helper closures to map data coordinates to screen coordinates
+        let map_angle = |theta: f32| -> f32 { zero_offset + sign * theta };
+        let map_r = |r: f32| -> f32 {
+            if (r_max - r_min).abs() < f32::EPSILON {
+                0.0
+            } else {
+                ((r - r_min) / (r_max - r_min)).max(0.0) * radius
+            }
+        };
+        let map_point = |theta: T, r: T| -> (f32, f32) {
+            let angle = map_angle(theta.to_f32());
+            let screen_r = map_r(r.to_f32());
+            (cx + screen_r * angle.cos(), cy - screen_r * angle.sin())
+        };
+
+        // --- Background ---
+        frame.draw_background(backend);
+
+        // --- Draw Title ---
+        frame.draw_title(backend, &layout);
+
+        // --- Grid, Angular Labels and Radial Ticks ---
+        draw_polar_grid(
+            backend,
+            self.grid,
+            &self.grid_config,
+            &self.axis_config,
+            &self.tick_config,
+            self.font,
+            cx,
+            cy,
+            radius,
+            &radial_ticks,
+            self.angular_divisions,
+            self.angle_unit,
+            map_angle,
+            map_r,
+        );
+
+        // --- Data Series Drawing, Clipped to the Circle ---
+        let clip = Clip::Circle {
+            center: (cx, cy),
+            radius,
+        };
+        backend.begin_clip("polarAreaClip", &clip);
+        draw_polar_series(backend, &self.data[..], &styles, map_point);
+        backend.end_clip();
+
+        // --- Legend Drawing ---
+        frame.draw_legend(backend, &legend_entries, &layout, || {
+            self.data
+                .iter()
+                .map(|s| s.data.iter().map(|&(theta, r)| map_point(theta, r)).collect())
+                .collect()
+        });
+        Ok(())
+    }
+}
+
+impl<'a, T: PlotValue, const N: usize> Chart for PolarPlot<'a, T, N> {
+    fn render(&self, backend: &mut dyn Backend) -> Result<(), Error> {
+        PolarPlot::render(self, backend)
+    }
+
+    fn svg_format(&self) -> SvgFormat {
+        self.svg_format
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{
+        Role, Segment,
+        recorder::{Recorder, rounded},
+    };
+    use std::f32::consts::FRAC_PI_2;
+
+    /// Renders a 400 by 400 plot without margins, centered on (200, 200)
+    fn record(plot: PolarPlot) -> Recorder {
+        let plot = PolarPlot {
+            dimensions: (400, 400),
+            margin: Margin::new(0.0, 0.0, 0.0, 0.0),
+            ..plot
+        };
+        let mut recorder = Recorder::default();
+        plot.render(&mut recorder).unwrap();
+        recorder
+    }
+
+    /// Screen position of every point of the first series
+    fn points(recorder: &Recorder) -> Vec<(f32, f32)> {
+        rounded(recorder.paths(Role::Series(0))[0])
+            .into_iter()
+            .map(|segment| match segment {
+                Segment::MoveTo(point) | Segment::LineTo(point) => point,
+                other => panic!("unexpected {other:?}"),
+            })
+            .collect()
+    }
+
+    /// Radius of the outer circle, inside the room kept for the angular labels
+    fn radius() -> f32 {
+        let tick_config = TickConfig::default();
+        200.0 - (tick_config.text_padding + tick_config.font_size * 2.0)
+    }
+
+    fn plot(data: Vec<(f32, f32)>) -> PolarPlot<'static> {
+        PolarPlot::builder()
+            .r_range(Range::Manual { min: 0.0, max: 1.0 })
+            .data([Series::builder().data(data).build()])
+            .build()
+    }
+
+    #[test]
+    fn angle_zero_and_direction_place_points() {
+        let r = radius();
+        let (east, west) = ((200.0 + r, 200.0), (200.0 - r, 200.0));
+        let (north, south) = ((200.0, 200.0 - r), (200.0, 200.0 + r));
+        // Where theta = 0 and theta = pi/2 land for each combination
+        let cases = [
+            (AngleZero::East, Direction::CounterClockwise, east, north),
+            (AngleZero::East, Direction::Clockwise, east, south),
+            (AngleZero::North, Direction::CounterClockwise, north, west),
+            (AngleZero::North, Direction::Clockwise, north, east),
+            (AngleZero::West, Direction::CounterClockwise, west, south),
+            (AngleZero::West, Direction::Clockwise, west, north),
+            (AngleZero::South, Direction::CounterClockwise, south, east),
+            (AngleZero::South, Direction::Clockwise, south, west),
+        ];
+        let round = |(x, y): (f32, f32)| ((x * 100.0).round() / 100.0, (y * 100.0).round() / 100.0);
+        for (angle_zero, direction, zero, quarter) in cases {
+            let data = vec![(0.0, 1.0), (FRAC_PI_2, 1.0)];
+            let plot = PolarPlot { angle_zero, direction, ..plot(data) };
+            let expected = [round(zero), round(quarter)];
+            assert_eq!(points(&record(plot)), expected, "{angle_zero:?} {direction:?}");
+        }
+    }
+
+    #[test]
+    fn angle_unit_only_changes_the_labels() {
+        let labels = |angle_unit| {
+            let plot = PolarPlot {
+                angle_zero: AngleZero::North,
+                direction: Direction::Clockwise,
+                angle_unit,
+                ..plot(vec![(0.0, 1.0), (FRAC_PI_2, 0.5)])
+            };
+            let recorder = record(plot);
+            let labels: Vec<(String, (f32, f32))> = recorder
+                .texts(Role::TickLabel)
+                .into_iter()
+                .map(|(label, position)| (label.to_string(), position))
+                .collect();
+            (points(&recorder), labels)
+        };
+        let (degree_points, degrees) = labels(AngleUnit::Degrees);
+        let (radian_points, radians) = labels(AngleUnit::Radians);
+        assert_eq!(degree_points, radian_points);
+
+        // A quarter turn clockwise from north is labelled to the east of the center
+        let position = |labels: &[(String, (f32, f32))], name: &str| {
+            labels.iter().find(|(label, _)| label == name).map(|(_, position)| *position)
+        };
+        let east = position(&degrees, "90°").unwrap();
+        assert!(east.0 > 200.0 + radius() && (east.1 - 200.0).abs() < 1e-3);
+        assert_eq!(position(&radians, "π/2"), Some(east));
+        assert_eq!(position(&degrees, "0°"), position(&radians, "0"));
+    }
+
+    #[test]
+    fn negative_r_maps_from_the_bottom_of_the_range() {
+        let r = radius();
+        // Automatic ranges start below zero to include negative values
+        let data = vec![(0.0, -1.0), (0.0, 0.0), (0.0, 1.0)];
+        let auto = PolarPlot { r_range: Range::Auto, ..plot(data) };
+        let expected = [(200.0, 200.0), (200.0 + r / 2.0, 200.0), (200.0 + r, 200.0)];
+        assert_eq!(points(&record(auto)), expected);
+
+        // Values below a manual minimum are drawn at the center rather than reflected
+        let clamped = plot(vec![(0.0, -0.5), (FRAC_PI_2, 0.5)]);
+        assert_eq!(points(&record(clamped)), [(200.0, 200.0), (200.0, 200.0 - r / 2.0)]);
+    }
+}
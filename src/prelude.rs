@@ -2,10 +2,20 @@
 //!
 //! This module re-exports the most commonly used items from the Quill library.
 
+pub use crate::backend::{Backend, SvgBackend};
 pub use crate::color::Color;
+pub use crate::colormap::Colormap;
+pub use crate::legend_entry::LegendEntry;
+pub use crate::ohlc::OhlcSeries;
+pub use crate::pie::PieChart;
 pub use crate::plot::Plot;
+pub use crate::polar::PolarPlot;
+#[cfg(any(feature = "png", feature = "pdf"))]
+pub use crate::render_context::RenderContext;
 pub use crate::series::Series;
-pub use crate::traits::PlotValue;
+pub use crate::slice::Slice;
+pub use crate::traits::{Chart, PlotValue};
+pub use crate::vector_field::VectorField;
 
 // Re-export all elements
 pub use crate::elements::*;
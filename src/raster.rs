@@ -0,0 +1,20 @@
+//! Rendered pixel data for embedding charts in GUIs, game engines and other image pipelines.
+//!
+//! This module is only available when the "png" feature is enabled.
+
+/// An image of 8-bit red, green, blue and alpha samples, row by row from the top left.
+///
+/// Samples are not premultiplied by alpha, so a transparent chart background gives pixels
+/// with an alpha of 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RgbaImage {
+    pub width: u32,
+    pub height: u32,
+    /// `width * height * 4` bytes
+    pub data: Vec<u8>,
+    /// Resolution the chart was rendered at: 96 times the scale factor
+    pub dpi: f32,
+}
+
+/// Screen resolution at a scale of 1.0, with one SVG pixel per CSS pixel
+pub(crate) const BASE_DPI: f32 = 96.0;
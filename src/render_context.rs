@@ -0,0 +1,259 @@
+//! Font setup shared across many exports.
+//!
+//! This module is only available when the "png" or "pdf" feature is enabled.
+
+use crate::{
+    error::Error,
+    text::system::{Metrics, database, with_metrics},
+    traits::Chart,
+};
+use std::{panic::resume_unwind, path::Path, sync::Arc, thread};
+use svg::Document;
+use usvg::fontdb::Database;
+
+#[cfg(feature = "pdf")]
+use crate::export::encode_pdf;
+#[cfg(feature = "jpeg")]
+use crate::export::encode_jpeg;
+#[cfg(feature = "webp")]
+use crate::export::encode_webp;
+#[cfg(feature = "png")]
+use crate::{
+    export::{encode_png, render_rgba},
+    raster::RgbaImage,
+};
+
+/// Fonts loaded once and reused to export any number of charts.
+///
+/// The chart methods such as `to_png` already share the system fonts, loaded on first use.
+/// A context adds fonts of your own, from files or from bytes bundled into the binary, without
+/// installing them. It is cheap to clone and can be shared across threads; see
+/// [`render_parallel`](Self::render_parallel).
+///
+/// Layout measures text with the same fonts, so labels set in a loaded font get margins
+/// that fit them.
+#[derive(Clone, Debug)]
+pub struct RenderContext {
+    fonts: Arc<Database>,
+    metrics: Arc<Metrics>,
+}
+
+impl Default for RenderContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderContext {
+    /// A context with the installed system fonts, or only the bundled font with the
+    /// "bundled-font" feature
+    pub fn new() -> Self {
+        Self {
+            fonts: Arc::clone(database()),
+            metrics: Arc::clone(Metrics::system()),
+        }
+    }
+
+    /// A context without any fonts, for output that depends only on the fonts loaded into it
+    pub fn empty() -> Self {
+        let fonts = Arc::new(Database::new());
+        Self {
+            metrics: Arc::new(Metrics::new(Arc::clone(&fonts))),
+            fonts,
+        }
+    }
+
+    /// Loads every face from a TrueType, OpenType or collection file
+    pub fn load_font_file(&mut self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let fonts = Arc::make_mut(&mut self.fonts);
+        let before = fonts.len();
+        fonts
+            .load_font_file(path)
+            .map_err(|err| Error::Font(format!("failed to read {}: {err}", path.display())))?;
+        if fonts.len() == before {
+            return Err(Error::Font(format!("no font faces found in {}", path.display())));
+        }
+        self.reset_metrics();
+        Ok(())
+    }
+
+    /// Loads every face from font data, such as a font bundled with `include_bytes!`
+    pub fn load_font_data(&mut self, data: Vec<u8>) -> Result<(), Error> {
+        let fonts = Arc::make_mut(&mut self.fonts);
+        let before = fonts.len();
+        fonts.load_font_data(data);
+        if fonts.len() == before {
+            return Err(Error::Font("no font faces found in the font data".to_string()));
+        }
+        self.reset_metrics();
+        Ok(())
+    }
+
+    /// Loads every font file in a directory and its subdirectories, skipping unreadable files
+    pub fn load_fonts_dir(&mut self, dir: impl AsRef<Path>) {
+        Arc::make_mut(&mut self.fonts).load_fonts_dir(dir);
+        self.reset_metrics();
+    }
+
+    /// Drops the advances measured so far, which may come from a face that a newly loaded
+    /// font now replaces
+    fn reset_metrics(&mut self) {
+        self.metrics = Arc::new(Metrics::new(Arc::clone(&self.fonts)));
+    }
+
+    /// Lays out a chart with its text measured against the fonts of this context
+    fn document(&self, chart: &impl Chart) -> Result<Document, Error> {
+        with_metrics(&self.metrics, || chart.to_document())
+    }
+
+    /// Number of font faces available for text
+    pub fn font_count(&self) -> usize {
+        self.fonts.len()
+    }
+
+    /// Renders every chart with `render`, spreading the work over the available CPU cores.
+    ///
+    /// Results are returned in the order of `charts`:
+    ///
+    /// ```
+    /// # #[cfg(feature = "png")] {
+    /// use quill::prelude::*;
+    ///
+    /// let plots: Vec<Plot> = (1..=8)
+    ///     .map(|i| {
+    ///         let series = Series::builder().data(vec![(0.0, 0.0), (1.0, i as f32)]).build();
+    ///         Plot::builder().data([series]).build()
+    ///     })
+    ///     .collect();
+    ///
+    /// let context = RenderContext::new();
+    /// let thumbnails = context.render_parallel(&plots, |context, plot| context.to_png_bytes(plot, 0.5));
+    /// assert_eq!(thumbnails.len(), plots.len());
+    /// # }
+    /// ```
+    pub fn render_parallel<C, R, F>(&self, charts: &[C], render: F) -> Vec<Result<R, Error>>
+    where
+        C: Sync,
+        R: Send,
+        F: Fn(&Self, &C) -> Result<R, Error> + Sync,
+    {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_size = charts.len().div_ceil(threads).max(1);
+        thread::scope(|scope| {
+            let handles: Vec<_> = charts
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    let render = &render;
+                    scope.spawn(move || {
+                        chunk.iter().map(|chart| render(self, chart)).collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap_or_else(|panic| resume_unwind(panic)))
+                .collect()
+        })
+    }
+
+    /// Saves a chart as a PNG file, scaling the size of the image by the given scale factor.
+    #[cfg(feature = "png")]
+    pub fn to_png(&self, chart: &impl Chart, filename: &str, scale: f32) -> Result<(), Error> {
+        std::fs::write(filename, self.to_png_bytes(chart, scale)?)?;
+        Ok(())
+    }
+
+    /// Converts a chart to PNG bytes, scaling the size of the image by the given scale factor.
+    #[cfg(feature = "png")]
+    pub fn to_png_bytes(&self, chart: &impl Chart, scale: f32) -> Result<Vec<u8>, Error> {
+        encode_png(&self.document(chart)?, &self.fonts, scale)
+    }
+
+    /// Renders a chart to unpremultiplied RGBA pixels, scaling the size of the image by the
+    /// given scale factor.
+    #[cfg(feature = "png")]
+    pub fn to_rgba(&self, chart: &impl Chart, scale: f32) -> Result<RgbaImage, Error> {
+        render_rgba(&self.document(chart)?, &self.fonts, scale)
+    }
+
+    /// Converts a chart to JPEG bytes at the given quality from 1 to 100, scaling the size of
+    /// the image by the given scale factor.
+    ///
+    /// This method is only available when the "jpeg" feature is enabled.
+    #[cfg(feature = "jpeg")]
+    pub fn to_jpeg_bytes(
+        &self,
+        chart: &impl Chart,
+        scale: f32,
+        quality: u8,
+    ) -> Result<Vec<u8>, Error> {
+        encode_jpeg(&self.document(chart)?, &self.fonts, scale, quality)
+    }
+
+    /// Converts a chart to lossless WebP bytes, scaling the size of the image by the given
+    /// scale factor.
+    ///
+    /// This method is only available when the "webp" feature is enabled.
+    #[cfg(feature = "webp")]
+    pub fn to_webp_bytes(&self, chart: &impl Chart, scale: f32) -> Result<Vec<u8>, Error> {
+        encode_webp(&self.document(chart)?, &self.fonts, scale)
+    }
+
+    /// Saves a chart as a vector PDF file with a page the size of its dimensions in points.
+    ///
+    /// This method is only available when the "pdf" feature is enabled.
+    #[cfg(feature = "pdf")]
+    pub fn to_pdf(&self, chart: &impl Chart, filename: &str) -> Result<(), Error> {
+        std::fs::write(filename, self.to_pdf_bytes(chart)?)?;
+        Ok(())
+    }
+
+    /// Converts a chart to PDF bytes, embedding the fonts used by its text.
+    ///
+    /// This method is only available when the "pdf" feature is enabled.
+    #[cfg(feature = "pdf")]
+    pub fn to_pdf_bytes(&self, chart: &impl Chart) -> Result<Vec<u8>, Error> {
+        encode_pdf(&self.document(chart)?, &self.fonts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::*, text::text_width};
+
+    const FONT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fonts/DejaVuSans.ttf");
+
+    fn plot() -> Plot<'static> {
+        Plot::builder()
+            .font("DejaVu Sans")
+            .legend(Legend::TopRightInside)
+            .data([Series::builder().name("series").data(vec![(0.0, 1.0), (1.0, 3.0)]).build()])
+            .build()
+    }
+
+    #[test]
+    fn loaded_font_is_used_for_measurement() {
+        let mut context = RenderContext::empty();
+        let measure = |context: &RenderContext| {
+            with_metrics(&context.metrics, || text_width("Quill 123", "DejaVu Sans", 12.0))
+        };
+        let fallback = measure(&context);
+        context.load_font_file(FONT).unwrap();
+        let loaded = measure(&context);
+        assert!(loaded != fallback, "{loaded} {fallback}");
+
+        // Clones share the fonts, and measurement outside a context is unaffected
+        assert_eq!(measure(&context.clone()), loaded);
+        assert_eq!(measure(&RenderContext::empty()), fallback);
+    }
+
+    #[test]
+    fn loaded_font_changes_the_layout() {
+        let mut context = RenderContext::empty();
+        let fallback = context.document(&plot()).unwrap().to_string();
+        context.load_font_file(FONT).unwrap();
+        assert!(context.document(&plot()).unwrap().to_string() != fallback);
+    }
+}
@@ -9,8 +9,9 @@ pub struct Series<'a, T = f32> {
     pub data: Vec<(T, T)>,
     #[builder(default = "")]
     pub name: &'a str,
-    #[builder(default = Color::Black, into)]
-    pub color: Color,
+    /// Line and marker color, taken from the plot's color cycle when unset
+    #[builder(into)]
+    pub color: Option<Color>,
     #[builder(default = Line::Solid)]
     pub line: Line,
     #[builder(default = Marker::None)]
@@ -21,10 +22,108 @@ pub struct Series<'a, T = f32> {
     pub line_width: f32,
     #[builder(default = Interpolation::Linear)]
     pub interpolation: Interpolation,
+    /// Fill color for the area between the series and its baseline, or the area
+    /// enclosed by the series on polar plots
+    #[builder(into)]
+    pub fill: Option<Color>,
+    /// Opacity of the fill from 0.0 (transparent) to 1.0, multiplied with the fill color's alpha
+    #[builder(default = 0.3)]
+    pub fill_opacity: f32,
+    /// Draw a vertical stem from the baseline to each point; `line` styles the stems
+    #[builder(default = false)]
+    pub stem: bool,
+    /// Baseline for stems and fills, zero when unset
+    pub baseline: Option<T>,
+    /// List this series in the legend
+    #[builder(default = true)]
+    pub show_in_legend: bool,
 }
 
 impl<'a, T> Default for Series<'a, T> {
     fn default() -> Self {
         Series::builder().data(vec![]).build()
     }
-}
\ No newline at end of file
+}
+
+/// How a series is drawn once the chart's color, line and marker cycles are applied
+#[derive(Clone, Debug)]
+pub struct SeriesStyle {
+    pub color: Color,
+    pub line: Line,
+    pub marker: Marker,
+}
+
+/// Resolves the style of every series. Series without a color take the colors of
+/// `color_cycle` in turn, falling back to black when it is empty. A non-empty `line_cycle`
+/// restyles solid-line series and a non-empty `marker_cycle` gives markers to series
+/// without them; each cycle only advances on the series it applies to.
+pub(crate) fn series_styles<T>(
+    series: &[Series<T>],
+    color_cycle: &[Color],
+    line_cycle: &[Line],
+    marker_cycle: &[Marker],
+) -> Vec<SeriesStyle> {
+    fn next<X: Clone>(cycle: &[X], index: &mut usize) -> X {
+        *index += 1;
+        cycle[(*index - 1) % cycle.len()].clone()
+    }
+
+    let (mut color_index, mut line_index, mut marker_index) = (0, 0, 0);
+    series
+        .iter()
+        .map(|s| SeriesStyle {
+            color: match &s.color {
+                Some(color) => color.clone(),
+                None if color_cycle.is_empty() => Color::Black,
+                None => next(color_cycle, &mut color_index),
+            },
+            line: match s.line {
+                Line::Solid if !line_cycle.is_empty() => next(line_cycle, &mut line_index),
+                line => line,
+            },
+            marker: match s.marker {
+                Marker::None if !marker_cycle.is_empty() => next(marker_cycle, &mut marker_index),
+                marker => marker,
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn colors(series: &[Series], cycle: &[Color]) -> Vec<Color> {
+        series_styles(series, cycle, &[], &[]).into_iter().map(|style| style.color).collect()
+    }
+
+    #[test]
+    fn series_without_a_color_take_the_cycle_in_order() {
+        let cycle = [Color::Red, Color::Green, Color::Blue];
+        let series: Vec<Series> = vec![Series::default(); 4];
+        assert_eq!(colors(&series, &cycle), [Color::Red, Color::Green, Color::Blue, Color::Red]);
+        assert_eq!(colors(&series[..2], &[]), [Color::Black, Color::Black]);
+    }
+
+    #[test]
+    fn explicit_colors_do_not_use_a_cycle_slot() {
+        let cycle = [Color::Red, Color::Green, Color::Blue];
+        let orange = Series::builder().data(vec![]).color(Color::Orange).build();
+        let series: [Series; 5] =
+            [Series::default(), orange.clone(), Series::default(), orange, Series::default()];
+        let expected = [Color::Red, Color::Orange, Color::Green, Color::Orange, Color::Blue];
+        assert_eq!(colors(&series, &cycle), expected);
+    }
+
+    #[test]
+    fn line_and_marker_cycles_skip_styled_series() {
+        let dotted =
+            Series::builder().data(vec![]).line(Line::Dotted).marker(Marker::Cross).build();
+        let series: [Series; 3] = [Series::default(), dotted, Series::default()];
+        let styles = series_styles(&series, &[], &[Line::Dashed, Line::DashDot], &[Marker::Circle]);
+        let lines: Vec<Line> = styles.iter().map(|style| style.line).collect();
+        let markers: Vec<Marker> = styles.iter().map(|style| style.marker).collect();
+        assert_eq!(lines, [Line::Dashed, Line::Dotted, Line::DashDot]);
+        assert_eq!(markers, [Marker::Circle, Marker::Cross, Marker::Circle]);
+    }
+}
@@ -0,0 +1,18 @@
+use crate::color::Color;
+use bon::Builder;
+
+#[derive(Clone, Builder)]
+pub struct Slice<'a> {
+    pub value: f64,
+    #[builder(default = "")]
+    pub name: &'a str,
+    /// Slice color, taken from the chart's color cycle when unset
+    #[builder(into)]
+    pub color: Option<Color>,
+    /// Offset of the slice away from the center, as a fraction of the radius
+    #[builder(default = 0.0)]
+    pub explode: f32,
+    /// List this slice in the legend
+    #[builder(default = true)]
+    pub show_in_legend: bool,
+}
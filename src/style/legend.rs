@@ -9,6 +9,17 @@ pub struct LegendConfig {
     pub item_height: f32,
     pub color_swatch_width: f32,
     pub text_offset: f32,
+    /// Optional heading drawn above the entries
+    pub title: Option<String>,
+    /// Number of columns for placements other than `TopOutside` and `BottomOutside`
+    pub columns: usize,
+    /// Horizontal gap between columns
+    pub column_spacing: f32,
+    pub background: Color,
+    /// Opacity of the background fill, from 0.0 (transparent) to 1.0 (opaque)
+    pub background_opacity: f32,
+    pub corner_radius: f32,
+    pub show_border: bool,
 }
 
 impl Default for LegendConfig {
@@ -21,6 +32,13 @@ impl Default for LegendConfig {
             item_height: 18.0,
             color_swatch_width: 15.0,
             text_offset: 5.0,
+            title: None,
+            columns: 1,
+            column_spacing: 15.0,
+            background: Color::White,
+            background_opacity: 1.0,
+            corner_radius: 0.0,
+            show_border: true,
         }
     }
 }
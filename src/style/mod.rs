@@ -3,6 +3,9 @@ mod grid;
 mod label;
 mod legend;
 mod margin;
+mod pie;
+mod terminal;
+mod theme;
 mod tick;
 mod title;
 
@@ -11,5 +14,8 @@ pub use grid::GridConfig;
 pub use label::LabelConfig;
 pub use legend::LegendConfig;
 pub use margin::Margin;
+pub use pie::PieConfig;
+pub use terminal::TerminalConfig;
+pub use theme::Theme;
 pub use tick::TickConfig;
 pub use title::TitleConfig;
@@ -0,0 +1,24 @@
+use crate::color::Color;
+
+#[derive(Clone, Debug)]
+pub struct PieConfig {
+    pub label_font_size: f32,
+    pub label_color: Color,
+    pub border_color: Color,
+    pub border_width: f32,
+    pub leader_color: Color,
+    pub leader_length: f32,
+}
+
+impl Default for PieConfig {
+    fn default() -> Self {
+        Self {
+            label_font_size: 12.0,
+            label_color: Color::Black,
+            border_color: Color::White,
+            border_width: 1.0,
+            leader_color: Color::DarkGray,
+            leader_length: 15.0,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::elements::TerminalSymbols;
+
+#[derive(Clone, Debug)]
+pub struct TerminalConfig {
+    /// Total width in characters, including tick labels
+    pub width: usize,
+    /// Total height in lines, including the title, labels and legend
+    pub height: usize,
+    pub symbols: TerminalSymbols,
+    /// Color series with 24-bit ANSI escape codes
+    pub color: bool,
+}
+
+impl Default for TerminalConfig {
+    fn default() -> Self {
+        Self {
+            width: 80,
+            height: 24,
+            symbols: TerminalSymbols::Braille,
+            color: true,
+        }
+    }
+}
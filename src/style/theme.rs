@@ -0,0 +1,206 @@
+use crate::{
+    color::Color,
+    elements::{Axis, Grid, Line, Marker, Palette, Tick},
+    style::*,
+};
+
+/// A complete visual style for a plot: every style configuration together with the
+/// background, font and series color cycle.
+///
+/// Apply one with `Plot::with_theme`, or start from a preset and adjust its fields.
+#[derive(Clone, Debug)]
+pub struct Theme<'a> {
+    pub background: Color,
+    pub plot_background: Option<Color>,
+    pub font: &'a str,
+    pub color_cycle: Vec<Color>,
+    pub line_cycle: Vec<Line>,
+    pub marker_cycle: Vec<Marker>,
+    pub axis: Axis,
+    pub tick: Tick,
+    pub grid: Grid,
+    pub title_config: TitleConfig,
+    pub x_label_config: LabelConfig,
+    pub y_label_config: LabelConfig,
+    pub tick_config: TickConfig,
+    pub legend_config: LegendConfig,
+    pub axis_config: AxisConfig,
+    pub grid_config: GridConfig,
+}
+
+impl Default for Theme<'_> {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl Theme<'_> {
+    /// The default look: white background, boxed axes and a solid light grid
+    pub fn light() -> Self {
+        Self {
+            background: Color::White,
+            plot_background: None,
+            font: "Times New Roman",
+            color_cycle: Palette::Category10.colors(),
+            line_cycle: Vec::new(),
+            marker_cycle: Vec::new(),
+            axis: Axis::Box,
+            tick: Tick::Inward,
+            grid: Grid::Solid,
+            title_config: TitleConfig::default(),
+            x_label_config: LabelConfig::default(),
+            y_label_config: LabelConfig::default(),
+            tick_config: TickConfig::default(),
+            legend_config: LegendConfig::default(),
+            axis_config: AxisConfig::default(),
+            grid_config: GridConfig::default(),
+        }
+    }
+
+    /// Light text and lines on a dark gray background
+    pub fn dark() -> Self {
+        let text = Color::Rgb(220, 220, 220);
+        let lines = Color::Rgb(160, 160, 160);
+        Self {
+            background: Color::Rgb(30, 30, 30),
+            plot_background: Some(Color::Rgb(40, 40, 40)),
+            font: "Arial, Helvetica, sans-serif",
+            color_cycle: Palette::Tableau10.colors(),
+            title_config: TitleConfig {
+                color: text.clone(),
+                ..TitleConfig::default()
+            },
+            x_label_config: LabelConfig {
+                color: text.clone(),
+                ..LabelConfig::default()
+            },
+            y_label_config: LabelConfig {
+                color: text.clone(),
+                ..LabelConfig::default()
+            },
+            tick_config: TickConfig {
+                label_color: text.clone(),
+                line_color: lines.clone(),
+                minor_tick_color: lines.clone(),
+                ..TickConfig::default()
+            },
+            legend_config: LegendConfig {
+                text_color: text,
+                border_color: Color::Rgb(90, 90, 90),
+                background: Color::Rgb(40, 40, 40),
+                ..LegendConfig::default()
+            },
+            axis_config: AxisConfig {
+                color: lines,
+                ..AxisConfig::default()
+            },
+            grid_config: GridConfig {
+                color: Color::Rgb(70, 70, 70),
+                minor_color: Color::Rgb(55, 55, 55),
+                ..GridConfig::default()
+            },
+            ..Self::light()
+        }
+    }
+
+    /// Open left and bottom axes without a grid or legend border
+    pub fn minimal() -> Self {
+        let lines = Color::Rgb(90, 90, 90);
+        Self {
+            font: "Helvetica, Arial, sans-serif",
+            axis: Axis::BottomLeft,
+            tick: Tick::Outward,
+            grid: Grid::None,
+            tick_config: TickConfig {
+                label_color: Color::Rgb(60, 60, 60),
+                line_color: lines.clone(),
+                minor_tick_color: lines.clone(),
+                ..TickConfig::default()
+            },
+            legend_config: LegendConfig {
+                show_border: false,
+                ..LegendConfig::default()
+            },
+            axis_config: AxisConfig {
+                color: lines,
+                line_width: 1.0,
+            },
+            ..Self::light()
+        }
+    }
+
+    /// White grid lines on a gray-blue plot area, in the style of seaborn's "darkgrid"
+    pub fn seaborn() -> Self {
+        let text = Color::Rgb(50, 50, 50);
+        Self {
+            plot_background: Some(Color::Rgb(234, 234, 242)),
+            font: "DejaVu Sans, Arial, sans-serif",
+            color_cycle: Palette::Seaborn.colors(),
+            tick: Tick::Outward,
+            title_config: TitleConfig {
+                color: text.clone(),
+                ..TitleConfig::default()
+            },
+            x_label_config: LabelConfig {
+                color: text.clone(),
+                ..LabelConfig::default()
+            },
+            y_label_config: LabelConfig {
+                color: text.clone(),
+                ..LabelConfig::default()
+            },
+            // Tick marks and axis lines disappear against the white background
+            tick_config: TickConfig {
+                label_color: text.clone(),
+                line_color: Color::White,
+                minor_tick_color: Color::White,
+                ..TickConfig::default()
+            },
+            legend_config: LegendConfig {
+                text_color: text,
+                show_border: false,
+                background: Color::Rgb(234, 234, 242),
+                background_opacity: 0.8,
+                corner_radius: 3.0,
+                ..LegendConfig::default()
+            },
+            axis_config: AxisConfig {
+                color: Color::White,
+                line_width: 1.0,
+            },
+            grid_config: GridConfig {
+                color: Color::White,
+                line_width: 1.0,
+                minor_color: Color::White,
+                minor_line_width: 0.5,
+            },
+            ..Self::light()
+        }
+    }
+
+    /// Black on white for print, telling series apart by gray level and dash pattern
+    pub fn publication() -> Self {
+        Self {
+            color_cycle: Palette::Grayscale.colors(),
+            line_cycle: vec![Line::Solid, Line::Dashed, Line::Dotted, Line::DashDot],
+            grid: Grid::None,
+            title_config: TitleConfig {
+                font_size: 16.0,
+                ..TitleConfig::default()
+            },
+            x_label_config: LabelConfig {
+                font_size: 12.0,
+                ..LabelConfig::default()
+            },
+            y_label_config: LabelConfig {
+                font_size: 12.0,
+                ..LabelConfig::default()
+            },
+            axis_config: AxisConfig {
+                color: Color::Black,
+                line_width: 1.0,
+            },
+            ..Self::light()
+        }
+    }
+}
@@ -0,0 +1,503 @@
+//! Text rendering of charts for terminals, drawn with braille or block characters.
+
+use crate::{
+    backend::{Backend, Clip, Path, Point, Rect, Role, Segment, ShapeStyle, Stroke, Text, TextStyle},
+    color::Color,
+    elements::{Line, TerminalSymbols},
+    error::Error,
+    legend_entry::LegendEntry,
+};
+
+/// Braille dot bits indexed by `[row][column]` within a 2×4 character cell
+const BRAILLE_BITS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Quadrant characters indexed by top left (1), top right (2), bottom left (4) and
+/// bottom right (8) bits
+const QUADRANTS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+];
+
+/// Pieces each cubic curve is split into when drawn as straight lines
+const CURVE_STEPS: usize = 8;
+
+/// A grid of characters, each holding 2×4 (braille) or 2×2 (block) dots, drawn in axis
+/// fractions with y increasing upwards.
+///
+/// As a [`Backend`], it draws the data of a chart rendered onto it: the primitives of series,
+/// OHLC series and vector fields inside the plot area clip, mapped from pixels to axis
+/// fractions through that clip. Lines and outlines are drawn, curves as short straight
+/// segments, and circles and square markers as single dots; fills and text are left out.
+pub(crate) struct Canvas {
+    columns: usize,
+    rows: usize,
+    symbols: TerminalSymbols,
+    cells: Vec<u8>,
+    colors: Vec<Option<Color>>,
+    /// Plot area in pixels, from the chart's first rectangular clip
+    plot_area: Option<Rect>,
+    role: Role,
+    clip_depth: usize,
+}
+
+impl Canvas {
+    pub(crate) fn new(columns: usize, rows: usize, symbols: TerminalSymbols) -> Self {
+        Self {
+            columns,
+            rows,
+            symbols,
+            cells: vec![0; columns * rows],
+            colors: vec![None; columns * rows],
+            plot_area: None,
+            role: Role::Background,
+            clip_depth: 0,
+        }
+    }
+
+    /// Dots per character horizontally and vertically
+    fn cell_size(&self) -> (usize, usize) {
+        match self.symbols {
+            TerminalSymbols::Braille => (2, 4),
+            TerminalSymbols::Block => (2, 2),
+        }
+    }
+
+    /// Converts axis fractions to dot coordinates, with the origin at the top left
+    fn to_dots(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let (cell_width, cell_height) = self.cell_size();
+        let width = (self.columns * cell_width) as f32 - 1.0;
+        let height = (self.rows * cell_height) as f32 - 1.0;
+        (x * width, (1.0 - y) * height)
+    }
+
+    fn set_dot(&mut self, x: i64, y: i64, color: &Color) {
+        let (cell_width, cell_height) = self.cell_size();
+        let (x_dots, y_dots) = (self.columns * cell_width, self.rows * cell_height);
+        if x < 0 || y < 0 || x as usize >= x_dots || y as usize >= y_dots {
+            return;
+        }
+        let (x, y) = (x as usize, y as usize);
+        let index = (y / cell_height) * self.columns + x / cell_width;
+        let (dx, dy) = (x % cell_width, y % cell_height);
+        self.cells[index] |= match self.symbols {
+            TerminalSymbols::Braille => BRAILLE_BITS[dy][dx],
+            TerminalSymbols::Block => 1 << (dy * 2 + dx),
+        };
+        // The last series drawn through a character colors all of it
+        self.colors[index] = Some(color.clone());
+    }
+
+    /// Draws a single dot at a point given in axis fractions
+    fn fraction_point(&mut self, point: (f32, f32), color: &Color) {
+        let (x, y) = self.to_dots(point);
+        if x.is_finite() && y.is_finite() {
+            self.set_dot(x.round() as i64, y.round() as i64, color);
+        }
+    }
+
+    /// Draws a straight line between two points given in axis fractions, clipped to the canvas
+    fn fraction_line(&mut self, from: (f32, f32), to: (f32, f32), color: &Color) {
+        let (x_max, y_max) = self.to_dots((1.0, 0.0));
+        let Some(((x0, y0), (x1, y1))) = clip(self.to_dots(from), self.to_dots(to), x_max, y_max)
+        else {
+            return;
+        };
+        let (mut x, mut y) = (x0.round() as i64, y0.round() as i64);
+        let (x_end, y_end) = (x1.round() as i64, y1.round() as i64);
+        let (dx, dy) = ((x_end - x).abs(), -(y_end - y).abs());
+        let (step_x, step_y) = ((x_end - x).signum(), (y_end - y).signum());
+        let mut error = dx + dy;
+        loop {
+            self.set_dot(x, y, color);
+            if x == x_end && y == y_end {
+                break;
+            }
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += step_x;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += step_y;
+            }
+        }
+    }
+
+    /// Axis fractions of a point in pixels, or `None` for primitives that are not drawn
+    fn data_point(&self, (x, y): Point) -> Option<(f32, f32)> {
+        let area = self.plot_area.filter(|_| self.clip_depth > 0)?;
+        matches!(self.role, Role::Series(_) | Role::Ohlc(_) | Role::VectorField(_))
+            .then(|| ((x - area.x) / area.width, 1.0 - (y - area.y) / area.height))
+    }
+
+    fn pixel_line(&mut self, from: Point, to: Point, color: &Color) {
+        if let (Some(from), Some(to)) = (self.data_point(from), self.data_point(to)) {
+            self.fraction_line(from, to, color);
+        }
+    }
+
+    fn pixel_point(&mut self, point: Point, color: &Color) {
+        if let Some(point) = self.data_point(point) {
+            self.fraction_point(point, color);
+        }
+    }
+
+    /// The characters of one row with their colors
+    fn row(&self, row: usize) -> impl Iterator<Item = (char, Option<&Color>)> {
+        (row * self.columns..(row + 1) * self.columns).map(|index| {
+            let bits = self.cells[index];
+            let symbol = match self.symbols {
+                TerminalSymbols::Braille if bits == 0 => ' ',
+                TerminalSymbols::Braille => char::from_u32(0x2800 + bits as u32).unwrap_or(' '),
+                TerminalSymbols::Block => QUADRANTS[bits as usize],
+            };
+            (symbol, self.colors[index].as_ref())
+        })
+    }
+}
+
+impl Backend for Canvas {
+    fn begin(&mut self, _width: f32, _height: f32) {}
+
+    fn line(&mut self, from: Point, to: Point, stroke: &Stroke) {
+        self.pixel_line(from, to, &stroke.color);
+    }
+
+    fn path(&mut self, path: &Path, style: &ShapeStyle) {
+        // Filled shapes such as areas and arrow heads are left out
+        let Some(stroke) = &style.stroke else { return };
+        let (mut current, mut start) = ((0.0, 0.0), (0.0, 0.0));
+        for segment in &path.segments {
+            match *segment {
+                Segment::MoveTo(point) => {
+                    (current, start) = (point, point);
+                }
+                Segment::LineTo(to) | Segment::ArcTo { to, .. } => {
+                    self.pixel_line(current, to, &stroke.color);
+                    current = to;
+                }
+                Segment::CubicTo(control1, control2, to) => {
+                    let bezier = |t: f32, a: f32, b: f32, c: f32, d: f32| {
+                        let u = 1.0 - t;
+                        u * u * u * a + 3.0 * u * u * t * b + 3.0 * u * t * t * c + t * t * t * d
+                    };
+                    for step in 1..=CURVE_STEPS {
+                        let t = step as f32 / CURVE_STEPS as f32;
+                        let next = (
+                            bezier(t, current.0, control1.0, control2.0, to.0),
+                            bezier(t, current.1, control1.1, control2.1, to.1),
+                        );
+                        self.pixel_line(current, next, &stroke.color);
+                        current = next;
+                    }
+                }
+                Segment::Close => {
+                    self.pixel_line(current, start, &stroke.color);
+                    current = start;
+                }
+            }
+        }
+    }
+
+    fn rect(&mut self, rect: &Rect, style: &ShapeStyle) {
+        match (&style.stroke, &style.fill) {
+            (Some(stroke), _) => {
+                let (x0, y0) = (rect.x, rect.y);
+                let (x1, y1) = (rect.x + rect.width, rect.y + rect.height);
+                let corners = [(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)];
+                for pair in corners.windows(2) {
+                    self.pixel_line(pair[0], pair[1], &stroke.color);
+                }
+            }
+            // Square markers; filled bars such as OHLC volume are left out
+            (None, Some(fill)) if matches!(self.role, Role::Series(_)) => {
+                let center = (rect.x + rect.width / 2.0, rect.y + rect.height / 2.0);
+                self.pixel_point(center, fill);
+            }
+            _ => {}
+        }
+    }
+
+    fn circle(&mut self, center: Point, _radius: f32, style: &ShapeStyle) {
+        if let Some(color) = style.fill.as_ref().or(style.stroke.as_ref().map(|s| &s.color)) {
+            self.pixel_point(center, color);
+        }
+    }
+
+    fn text(&mut self, _text: &Text, _style: &TextStyle) {}
+
+    fn begin_group(&mut self) {}
+
+    fn end_group(&mut self) {}
+
+    fn begin_clip(&mut self, _id: &str, clip: &Clip) {
+        if let (None, Clip::Rect(rect)) = (self.plot_area, clip) {
+            self.plot_area = Some(*rect);
+        }
+        self.clip_depth += 1;
+    }
+
+    fn end_clip(&mut self) {
+        self.clip_depth = self.clip_depth.saturating_sub(1);
+    }
+
+    fn set_role(&mut self, role: Role) {
+        self.role = role;
+    }
+}
+
+/// Clips a segment to the box from (0, 0) to (`x_max`, `y_max`) with the Liang-Barsky
+/// algorithm, returning `None` when it lies entirely outside.
+fn clip(
+    (x0, y0): (f32, f32),
+    (x1, y1): (f32, f32),
+    x_max: f32,
+    y_max: f32,
+) -> Option<((f32, f32), (f32, f32))> {
+    if ![x0, y0, x1, y1].iter().all(|v| v.is_finite()) {
+        return None;
+    }
+    let (dx, dy) = (x1 - x0, y1 - y0);
+    let (mut t0, mut t1) = (0.0f32, 1.0f32);
+    for (p, q) in [(-dx, x0), (dx, x_max - x0), (-dy, y0), (dy, y_max - y0)] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+    (t0 <= t1).then_some(((x0 + t0 * dx, y0 + t0 * dy), (x0 + t1 * dx, y0 + t1 * dy)))
+}
+
+/// Text around the canvas: labels, ticks and legend
+pub(crate) struct Frame<'a> {
+    pub title: &'a str,
+    pub x_label: String,
+    pub y_label: String,
+    /// Axis fraction and label of each x tick
+    pub x_ticks: Vec<(f32, String)>,
+    /// Axis fraction and label of each y tick
+    pub y_ticks: Vec<(f32, String)>,
+    pub legend: Vec<LegendEntry<'a>>,
+    pub color: bool,
+}
+
+impl Frame<'_> {
+    /// Width of the y tick label column, including the space before the axis
+    pub(crate) fn gutter_width(&self) -> usize {
+        self.y_ticks.iter().map(|(_, label)| label.chars().count()).max().unwrap_or(0) + 1
+    }
+
+    /// Lines taken by everything other than the canvas rows, for a frame `width` wide
+    fn extra_lines(&self, width: usize) -> usize {
+        let optional = [self.title, &self.y_label, &self.x_label];
+        // Axis line and tick labels, plus the optional labels and legend
+        2 + optional.iter().filter(|text| !text.is_empty()).count() + self.legend_lines(width).len()
+    }
+
+    /// Canvas size in characters for a frame of `width` by `height`
+    pub(crate) fn canvas_size(&self, width: usize, height: usize) -> Result<(usize, usize), Error> {
+        let columns = width.saturating_sub(self.gutter_width() + 1);
+        let rows = height.saturating_sub(self.extra_lines(width));
+        if columns < 2 || rows < 2 {
+            return Err(Error::PlotAreaTooSmall {
+                width: columns as f32,
+                height: rows as f32,
+            });
+        }
+        Ok((columns, rows))
+    }
+
+    /// Legend entries packed into lines no wider than `width`
+    fn legend_lines(&self, width: usize) -> Vec<Vec<&LegendEntry<'_>>> {
+        let mut lines: Vec<Vec<&LegendEntry>> = Vec::new();
+        let mut line_width = 0;
+        for entry in self.legend.iter().filter(|entry| !entry.name.is_empty()) {
+            // Two-character glyph, a space and the name, separated by three spaces
+            let entry_width = 3 + entry.name.chars().count();
+            match lines.last_mut() {
+                Some(line) if line_width + 3 + entry_width <= width => {
+                    line.push(entry);
+                    line_width += 3 + entry_width;
+                }
+                _ => {
+                    lines.push(vec![entry]);
+                    line_width = entry_width;
+                }
+            }
+        }
+        lines
+    }
+
+    fn paint(&self, text: &str, color: Option<&Color>) -> String {
+        match color {
+            Some(color) if self.color => {
+                let (r, g, b) = color.to_rgb();
+                format!("\x1b[38;2;{r};{g};{b}m{text}\x1b[0m")
+            }
+            _ => text.to_string(),
+        }
+    }
+
+    /// Lays out the frame around `canvas` as lines of text
+    pub(crate) fn render(&self, canvas: &Canvas, width: usize) -> String {
+        let gutter = self.gutter_width();
+        let columns = canvas.columns;
+        let mut lines: Vec<String> = Vec::new();
+
+        if !self.title.is_empty() {
+            lines.push(centered(self.title, gutter + 1 + columns));
+        }
+        if !self.y_label.is_empty() {
+            lines.push(self.y_label.clone());
+        }
+
+        let tick_row = |fraction: f32| ((1.0 - fraction) * (canvas.rows - 1) as f32).round() as usize;
+        for row in 0..canvas.rows {
+            let label = self
+                .y_ticks
+                .iter()
+                .find(|(fraction, _)| tick_row(*fraction) == row)
+                .map(|(_, label)| label.as_str());
+            let mut line = format!("{:>width$} ", label.unwrap_or(""), width = gutter - 1);
+            line.push(if label.is_some() { '┤' } else { '│' });
+
+            // Group neighbouring characters of the same color into one escape sequence
+            let mut run = String::new();
+            let mut run_color: Option<&Color> = None;
+            for (symbol, color) in canvas.row(row) {
+                if color != run_color && !run.is_empty() {
+                    line.push_str(&self.paint(&run, run_color));
+                    run.clear();
+                }
+                run_color = color;
+                run.push(symbol);
+            }
+            line.push_str(&self.paint(&run, run_color));
+            lines.push(line.trim_end().to_string());
+        }
+
+        let tick_column = |fraction: f32| (fraction * (columns - 1) as f32).round() as usize;
+        let mut axis: Vec<char> = vec!['─'; columns];
+        for (fraction, _) in &self.x_ticks {
+            axis[tick_column(*fraction)] = '┬';
+        }
+        lines.push(format!("{}└{}", " ".repeat(gutter), axis.iter().collect::<String>()));
+
+        // Tick labels centered under their ticks, skipping any that would overlap
+        let mut labels: Vec<char> = vec![' '; gutter + 1 + columns];
+        let mut next_free = 0;
+        for (fraction, label) in &self.x_ticks {
+            let length = label.chars().count();
+            let center = gutter + 1 + tick_column(*fraction);
+            let start = center.saturating_sub(length / 2).min(labels.len().saturating_sub(length));
+            if start < next_free {
+                continue;
+            }
+            // Labels longer than the whole row are cut off at its end
+            for (slot, c) in labels[start..].iter_mut().zip(label.chars()) {
+                *slot = c;
+            }
+            next_free = start + length + 1;
+        }
+        lines.push(labels.iter().collect::<String>().trim_end().to_string());
+
+        if !self.x_label.is_empty() {
+            let label = centered(&self.x_label, columns);
+            lines.push(format!("{}{}", " ".repeat(gutter + 1), label));
+        }
+
+        for entries in self.legend_lines(width) {
+            let items: Vec<String> = entries
+                .iter()
+                .map(|entry| {
+                    let glyph = if entry.line != Line::None {
+                        "──"
+                    } else if entry.fill.is_some() {
+                        "██"
+                    } else {
+                        "••"
+                    };
+                    format!("{} {}", self.paint(glyph, Some(&entry.color)), entry.name)
+                })
+                .collect();
+            lines.push(items.join("   "));
+        }
+
+        let mut output = lines.join("\n");
+        output.push('\n');
+        output
+    }
+}
+
+/// Pads `text` on the left to center it within `width` characters
+fn centered(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(text.chars().count()) / 2;
+    format!("{}{}", " ".repeat(padding), text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(x_ticks: Vec<(f32, String)>) -> Frame<'static> {
+        Frame {
+            title: "",
+            x_label: String::new(),
+            y_label: String::new(),
+            x_ticks,
+            y_ticks: vec![(0.0, "0".to_string()), (1.0, "1".to_string())],
+            legend: Vec::new(),
+            color: false,
+        }
+    }
+
+    #[test]
+    fn backend_draws_only_data_inside_the_plot_area() {
+        let mut canvas = Canvas::new(4, 2, TerminalSymbols::Block);
+        let stroke = Stroke::new(Color::Red, 1.0);
+        canvas.begin(100.0, 100.0);
+        canvas.set_role(Role::Grid);
+        canvas.line((0.0, 0.0), (100.0, 100.0), &stroke);
+        canvas.begin_clip("plotAreaClip", &Clip::Rect(Rect::new(10.0, 10.0, 80.0, 80.0)));
+        canvas.set_role(Role::Series(0));
+        // A horizontal line along the bottom of the plot area
+        canvas.path(&Path::new().move_to((10.0, 90.0)).line_to((90.0, 90.0)), &ShapeStyle::stroked(stroke.clone()));
+        canvas.path(&Path::new().move_to((10.0, 10.0)).line_to((90.0, 10.0)).close(), &ShapeStyle::filled(Color::Red));
+        canvas.end_clip();
+        canvas.set_role(Role::Legend);
+        canvas.line((10.0, 10.0), (90.0, 10.0), &stroke);
+
+        let rows: Vec<String> = (0..2).map(|row| canvas.row(row).map(|(c, _)| c).collect()).collect();
+        assert_eq!(rows, ["    ", "▄▄▄▄"]);
+    }
+
+    #[test]
+    fn long_x_tick_label_is_cut_at_the_row_end() {
+        let label = "123456789.123456789".to_string();
+        let frame = frame(vec![(0.0, label.clone()), (1.0, label)]);
+        let (columns, rows) = frame.canvas_size(8, 6).unwrap();
+        let canvas = Canvas::new(columns, rows, TerminalSymbols::Braille);
+        let output = frame.render(&canvas, 8);
+        let last = output.lines().last().unwrap();
+        assert_eq!(last, &"123456789.123456789"[..frame.gutter_width() + 1 + columns]);
+    }
+
+    #[test]
+    fn overlapping_x_tick_labels_are_skipped() {
+        let ticks = vec![(0.0, "1000".to_string()), (0.1, "2000".to_string()), (1.0, "3000".to_string())];
+        let frame = frame(ticks);
+        let (columns, rows) = frame.canvas_size(40, 10).unwrap();
+        let canvas = Canvas::new(columns, rows, TerminalSymbols::Block);
+        let output = frame.render(&canvas, 40);
+        let last = output.lines().last().unwrap();
+        assert!(last.contains("1000") && last.contains("3000") && !last.contains("2000"), "{last}");
+    }
+}
@@ -0,0 +1,355 @@
+//! Text measurement used for layout.
+//!
+//! With the `png` or `pdf` feature, strings are measured with the glyph advances of the
+//! installed font that best matches the requested family. Without it, or when no
+//! matching font is installed, widths come from a bundled table of the standard
+//! Helvetica, Times and Courier metrics.
+//!
+//! With the `bundled-font` feature, the embedded DejaVu Sans replaces the installed fonts
+//! for every family, so measurement and raster output are the same on every machine.
+
+/// Returns the advance width of `text` in pixels when set in `font_family` at `font_size`.
+///
+/// `font_family` accepts a CSS-style comma separated list, e.g. `"Arial, sans-serif"`.
+pub fn text_width(text: &str, font_family: &str, font_size: f32) -> f32 {
+    #[cfg(any(feature = "png", feature = "pdf"))]
+    if let Some(width) = system::text_width(text, font_family) {
+        return width * font_size;
+    }
+    fallback_width(text, font_family) * font_size
+}
+
+/// Returns the width of the widest string in `texts`.
+pub fn max_text_width<'a, I>(texts: I, font_family: &str, font_size: f32) -> f32
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    texts
+        .into_iter()
+        .map(|text| text_width(text, font_family, font_size))
+        .fold(0.0f32, f32::max)
+}
+
+/// Widths in 1/1000 em for ASCII 32..=126 of the standard Helvetica metrics
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, // ' '..'/'
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, // '0'..'?'
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778, // '@'..'O'
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556, // 'P'..'_'
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556, // '`'..'o'
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584, // 'p'..'~'
+];
+
+/// Widths in 1/1000 em for ASCII 32..=126 of the standard Times-Roman metrics
+const TIMES_WIDTHS: [u16; 95] = [
+    250, 333, 408, 500, 500, 833, 778, 180, 333, 333, 500, 564, 250, 333, 250, 278, // ' '..'/'
+    500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 278, 278, 564, 564, 564, 444, // '0'..'?'
+    921, 722, 667, 667, 722, 611, 556, 722, 722, 333, 389, 722, 611, 889, 722, 722, // '@'..'O'
+    556, 722, 667, 556, 611, 722, 722, 944, 722, 722, 611, 333, 278, 333, 469, 500, // 'P'..'_'
+    333, 444, 500, 444, 500, 444, 333, 500, 500, 278, 278, 500, 278, 778, 500, 500, // '`'..'o'
+    500, 500, 333, 389, 278, 500, 500, 722, 500, 500, 444, 480, 200, 480, 541, // 'p'..'~'
+];
+
+#[derive(Clone, Copy)]
+enum FallbackFace {
+    Sans,
+    Serif,
+    Mono,
+}
+
+impl FallbackFace {
+    /// Picks the metric table for the first family in the list that gives a hint
+    fn for_family(font_family: &str) -> Self {
+        for family in font_family.split(',') {
+            let family = family.trim().trim_matches(['"', '\'']).to_ascii_lowercase();
+            if family.contains("mono") || family.contains("courier") || family.contains("consol") {
+                return FallbackFace::Mono;
+            }
+            if family.contains("sans") || family.contains("arial") || family.contains("helvetica") {
+                return FallbackFace::Sans;
+            }
+            if family.contains("serif")
+                || family.contains("times")
+                || family.contains("georgia")
+                || family.contains("garamond")
+            {
+                return FallbackFace::Serif;
+            }
+        }
+        FallbackFace::Sans
+    }
+
+    /// Advance of a single character in em
+    fn advance(self, c: char) -> f32 {
+        if let Some(index) = (c as u32).checked_sub(32).filter(|i| *i < 95) {
+            return match self {
+                FallbackFace::Sans => HELVETICA_WIDTHS[index as usize] as f32 / 1000.0,
+                FallbackFace::Serif => TIMES_WIDTHS[index as usize] as f32 / 1000.0,
+                FallbackFace::Mono => 0.6,
+            };
+        }
+        match c {
+            // Combining marks and zero width characters
+            '\u{0300}'..='\u{036F}' | '\u{200B}'..='\u{200F}' => 0.0,
+            // CJK, Hangul and fullwidth forms occupy a full em
+            '\u{1100}'..='\u{115F}'
+            | '\u{2E80}'..='\u{A4CF}'
+            | '\u{AC00}'..='\u{D7A3}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{FE30}'..='\u{FE4F}'
+            | '\u{FF00}'..='\u{FF60}'
+            | '\u{FFE0}'..='\u{FFE6}'
+            | '\u{20000}'..='\u{3FFFD}' => 1.0,
+            '·' | '•' => 0.35,
+            _ => match self {
+                FallbackFace::Mono => 0.6,
+                _ => 0.55,
+            },
+        }
+    }
+}
+
+/// Width of `text` in em using the bundled metric tables
+fn fallback_width(text: &str, font_family: &str) -> f32 {
+    let face = FallbackFace::for_family(font_family);
+    text.chars().map(|c| face.advance(c)).sum()
+}
+
+#[cfg(any(feature = "png", feature = "pdf"))]
+pub(crate) mod system {
+    use super::FallbackFace;
+    use usvg::fontdb::{Database, Family, Query};
+    use std::{
+        cell::RefCell,
+        collections::HashMap,
+        sync::{Arc, Mutex, OnceLock},
+    };
+
+    /// System fonts, loaded once on first use and shared with every export
+    #[cfg(not(feature = "bundled-font"))]
+    pub(crate) fn database() -> &'static Arc<Database> {
+        static DATABASE: OnceLock<Arc<Database>> = OnceLock::new();
+        DATABASE.get_or_init(|| {
+            let mut db = Database::new();
+            db.load_system_fonts();
+            Arc::new(db)
+        })
+    }
+
+    /// The bundled font alone, standing in for every family so that output does not depend
+    /// on the installed fonts
+    #[cfg(feature = "bundled-font")]
+    pub(crate) fn database() -> &'static Arc<Database> {
+        static DATABASE: OnceLock<Arc<Database>> = OnceLock::new();
+        DATABASE.get_or_init(|| {
+            let mut db = Database::new();
+            db.load_font_data(BUNDLED_FONT.to_vec());
+            // Unknown names fall back to the serif family in both measurement and rendering
+            db.set_serif_family(BUNDLED_FAMILY);
+            db.set_sans_serif_family(BUNDLED_FAMILY);
+            db.set_monospace_family(BUNDLED_FAMILY);
+            db.set_cursive_family(BUNDLED_FAMILY);
+            db.set_fantasy_family(BUNDLED_FAMILY);
+            Arc::new(db)
+        })
+    }
+
+    /// DejaVu Sans, under the Bitstream Vera license in `fonts/LICENSE-DejaVu.txt`
+    #[cfg(feature = "bundled-font")]
+    const BUNDLED_FONT: &[u8] = include_bytes!("../fonts/DejaVuSans.ttf");
+    #[cfg(feature = "bundled-font")]
+    const BUNDLED_FAMILY: &str = "DejaVu Sans";
+
+    /// Advances in em of every character mapped by a font face
+    type Advances = HashMap<char, f32>;
+
+    /// A font database and the advances read from it so far, one entry per requested family
+    #[derive(Debug)]
+    pub(crate) struct Metrics {
+        fonts: Arc<Database>,
+        faces: Mutex<HashMap<String, Option<Arc<Advances>>>>,
+    }
+
+    impl Metrics {
+        pub(crate) fn new(fonts: Arc<Database>) -> Self {
+            Self {
+                fonts,
+                faces: Mutex::new(HashMap::new()),
+            }
+        }
+
+        /// Metrics of the fonts returned by [`database`], shared by every chart
+        pub(crate) fn system() -> &'static Arc<Metrics> {
+            static METRICS: OnceLock<Arc<Metrics>> = OnceLock::new();
+            METRICS.get_or_init(|| Arc::new(Metrics::new(Arc::clone(database()))))
+        }
+
+        /// Advances of the face that best matches `font_family`, resolved and read once per
+        /// family and then shared by every measurement
+        fn face_advances(&self, font_family: &str) -> Option<Arc<Advances>> {
+            if let Some(advances) = self.faces.lock().ok()?.get(font_family) {
+                return advances.clone();
+            }
+            // Resolved outside the lock; a concurrent resolution of the same family is harmless
+            let advances = load_advances(&self.fonts, font_family).map(Arc::new);
+            self.faces
+                .lock()
+                .ok()?
+                .entry(font_family.to_string())
+                .or_insert(advances)
+                .clone()
+        }
+    }
+
+    thread_local! {
+        /// Metrics that replace the system ones while a chart is laid out on this thread
+        static SCOPED: RefCell<Option<Arc<Metrics>>> = const { RefCell::new(None) };
+    }
+
+    /// Runs `f` with text measured against `metrics` instead of the system fonts, so that
+    /// layout matches output rendered with the same fonts
+    pub(crate) fn with_metrics<R>(metrics: &Arc<Metrics>, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<Arc<Metrics>>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                SCOPED.with(|scoped| *scoped.borrow_mut() = previous);
+            }
+        }
+        let _restore = Restore(SCOPED.with(|scoped| scoped.replace(Some(Arc::clone(metrics)))));
+        f()
+    }
+
+    /// Width of `text` in em using the best matching installed font, if any
+    pub(crate) fn text_width(text: &str, font_family: &str) -> Option<f32> {
+        let advances = SCOPED.with(|scoped| match scoped.borrow().as_ref() {
+            Some(metrics) => metrics.face_advances(font_family),
+            None => Metrics::system().face_advances(font_family),
+        })?;
+        let fallback = FallbackFace::for_family(font_family);
+        Some(
+            text.chars()
+                .map(|c| advances.get(&c).copied().unwrap_or_else(|| fallback.advance(c)))
+                .sum(),
+        )
+    }
+
+    /// Reads the advances of the face in `db` that best matches `font_family`
+    fn load_advances(db: &Database, font_family: &str) -> Option<Advances> {
+        let names: Vec<String> = font_family
+            .split(',')
+            .map(|family| family.trim().trim_matches(['"', '\'']).to_string())
+            .filter(|family| !family.is_empty())
+            .collect();
+        let mut families: Vec<Family> = names
+            .iter()
+            .map(|name| match name.to_ascii_lowercase().as_str() {
+                "serif" => Family::Serif,
+                "sans-serif" => Family::SansSerif,
+                "monospace" => Family::Monospace,
+                "cursive" => Family::Cursive,
+                "fantasy" => Family::Fantasy,
+                _ => Family::Name(name),
+            })
+            .collect();
+        // Same default as the SVG renderer when no listed family is installed
+        families.push(Family::Serif);
+
+        let id = db.query(&Query {
+            families: &families,
+            ..Query::default()
+        })?;
+        db.with_face_data(id, |data, index| {
+            let face = ttf_parser::Face::parse(data, index).ok()?;
+            let units_per_em = face.units_per_em() as f32;
+            let mut advances = Advances::new();
+            for subtable in face.tables().cmap?.subtables {
+                if !subtable.is_unicode() {
+                    continue;
+                }
+                subtable.codepoints(|codepoint| {
+                    let Some(c) = char::from_u32(codepoint) else { return };
+                    if let Some(advance) = face.glyph_index(c).and_then(|glyph| face.glyph_hor_advance(glyph)) {
+                        advances.insert(c, advance as f32 / units_per_em);
+                    }
+                });
+            }
+            Some(advances)
+        })?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallback_tables_follow_the_family_hint() {
+        assert_eq!(fallback_width("A", "Arial"), 0.667);
+        assert_eq!(fallback_width("A", "'Times New Roman', serif"), 0.722);
+        assert_eq!(fallback_width("Wi", "Courier New"), 1.2);
+        // The first family that gives a hint wins, and unknown names use sans metrics
+        assert_eq!(fallback_width("A", "Consolas, Arial"), 0.6);
+        assert_eq!(fallback_width("A", "No Such Font"), 0.667);
+    }
+
+    #[test]
+    fn fallback_widths_outside_ascii() {
+        assert_eq!(fallback_width("漢字", "serif"), 2.0);
+        assert_eq!(fallback_width("e\u{0301}", "sans-serif"), fallback_width("e", "sans-serif"));
+        assert_eq!(fallback_width("·", "serif"), 0.35);
+    }
+
+    #[test]
+    fn wider_strings_measure_wider() {
+        for family in ["sans-serif", "serif", "No Such Font"] {
+            let narrow = text_width("iii", family, 12.0);
+            let wide = text_width("WWW", family, 12.0);
+            assert!(narrow > 0.0 && wide > narrow, "{family}");
+            assert!(text_width("WWWW", family, 12.0) > wide, "{family}");
+            assert_eq!(max_text_width(["iii", "WWW", ""], family, 12.0), wide);
+            assert!((text_width("WWW", family, 24.0) - 2.0 * wide).abs() < 1e-3);
+        }
+    }
+
+    #[cfg(any(feature = "png", feature = "pdf"))]
+    mod system_fonts {
+        use super::super::{
+            fallback_width,
+            system::{Metrics, with_metrics},
+            text_width,
+        };
+        use std::sync::Arc;
+        use usvg::fontdb::Database;
+
+        fn measure(fonts: Database, text: &str, family: &str) -> f32 {
+            let metrics = Arc::new(Metrics::new(Arc::new(fonts)));
+            with_metrics(&metrics, || text_width(text, family, 10.0))
+        }
+
+        fn dejavu() -> Database {
+            let mut fonts = Database::new();
+            fonts.load_font_data(include_bytes!("../fonts/DejaVuSans.ttf").to_vec());
+            fonts
+        }
+
+        #[test]
+        fn font_advances_replace_the_fallback_tables() {
+            let font = measure(dejavu(), "WWW", "DejaVu Sans");
+            assert!(font > measure(dejavu(), "iii", "DejaVu Sans"));
+            assert!(font != fallback_width("WWW", "DejaVu Sans") * 10.0);
+            // Names are matched against the fonts, with generic families passed through
+            assert_eq!(measure(dejavu(), "WWW", "'No Such Font', DejaVu Sans"), font);
+        }
+
+        #[test]
+        fn unknown_family_falls_back_without_a_font() {
+            for fonts in [Database::new(), dejavu()] {
+                let width = measure(fonts, "WWW", "No Such Font");
+                assert!(width.is_finite() && width > 0.0);
+            }
+            let fallback = fallback_width("WWW", "serif") * 10.0;
+            assert_eq!(measure(Database::new(), "WWW", "serif"), fallback);
+        }
+    }
+}
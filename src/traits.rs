@@ -1,4 +1,168 @@
-use std::fmt::Display;
+use crate::{
+    backend::{Backend, SvgBackend},
+    elements::SvgFormat,
+    error::Error,
+    export::{format_svg, write_svg},
+};
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{BufWriter, Write},
+};
+use svg::Document;
+use svg::node::element::Group;
+
+#[cfg(any(feature = "png", feature = "pdf"))]
+use crate::text::system::database;
+#[cfg(feature = "pdf")]
+use crate::export::encode_pdf;
+#[cfg(feature = "jpeg")]
+use crate::export::encode_jpeg;
+#[cfg(feature = "webp")]
+use crate::export::encode_webp;
+#[cfg(feature = "png")]
+use crate::{
+    export::{encode_png, render_rgba},
+    raster::RgbaImage,
+};
+
+/// A chart that draws itself on a [`Backend`], letting a `RenderContext` export any chart type.
+///
+/// Every export method is provided on top of `render`, so each chart type shares one
+/// implementation of them.
+pub trait Chart {
+    /// Draws the chart on `backend`, emitting nothing when an error is returned
+    fn render(&self, backend: &mut dyn Backend) -> Result<(), Error>;
+
+    /// Text layout of the chart's SVG output
+    fn svg_format(&self) -> SvgFormat {
+        SvgFormat::default()
+    }
+
+    /// Renders the chart to an SVG document
+    fn to_document(&self) -> Result<Document, Error> {
+        let mut backend = SvgBackend::new();
+        self.render(&mut backend)?;
+        Ok(backend.into_document())
+    }
+
+    /// Renders the chart to an SVG `<g>` element for embedding in a larger document.
+    ///
+    /// The group has no size, generated ids start with `id_prefix`, and elements carry
+    /// `quill-` CSS classes such as `quill-grid` or `quill-series quill-series-0`.
+    fn to_fragment(&self, id_prefix: &str) -> Result<Group, Error> {
+        let mut backend = SvgBackend::fragment(id_prefix);
+        self.render(&mut backend)?;
+        Ok(backend.into_fragment())
+    }
+
+    /// Saves the chart as an SVG file
+    fn to_svg(&self, filename: &str) -> Result<(), Error> {
+        let mut file = BufWriter::new(File::create(filename)?);
+        self.write_svg(&mut file)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// Writes the chart as SVG to any writer, such as a response body or a buffer
+    fn write_svg(&self, writer: &mut dyn Write) -> Result<(), Error> {
+        write_svg(&self.to_document()?, self.svg_format(), writer)
+    }
+
+    /// Renders the chart to an SVG string
+    fn to_svg_string(&self) -> Result<String, Error> {
+        Ok(format_svg(&self.to_document()?, self.svg_format()))
+    }
+
+    /// Renders the chart to an SVG `<g>` element for inline embedding in HTML, without a size
+    /// and with generated ids prefixed by `id_prefix`, so several charts can share one page.
+    ///
+    /// Elements carry `quill-` CSS classes, such as `quill-title` or `quill-series-0`.
+    fn to_svg_fragment(&self, id_prefix: &str) -> Result<String, Error> {
+        Ok(format_svg(&self.to_fragment(id_prefix)?, self.svg_format()))
+    }
+
+    /// Saves the chart as a PNG file, scaling the size of the image by the given scale factor.
+    ///
+    /// This method is only available when the "png" feature is enabled.
+    #[cfg(feature = "png")]
+    fn to_png(&self, filename: &str, scale: f32) -> Result<(), Error> {
+        std::fs::write(filename, self.to_png_bytes(scale)?)?;
+        Ok(())
+    }
+
+    /// Converts the chart to PNG bytes, scaling the size of the image by the given scale factor.
+    ///
+    /// This method is only available when the "png" feature is enabled.
+    #[cfg(feature = "png")]
+    fn to_png_bytes(&self, scale: f32) -> Result<Vec<u8>, Error> {
+        encode_png(&self.to_document()?, database(), scale)
+    }
+
+    /// Renders the chart to unpremultiplied RGBA pixels, scaling the size of the image by the
+    /// given scale factor.
+    ///
+    /// This method is only available when the "png" feature is enabled.
+    #[cfg(feature = "png")]
+    fn to_rgba(&self, scale: f32) -> Result<RgbaImage, Error> {
+        render_rgba(&self.to_document()?, database(), scale)
+    }
+
+    /// Saves the chart as a JPEG file at the given quality from 1 to 100, scaling the size of
+    /// the image by the given scale factor.
+    ///
+    /// This method is only available when the "jpeg" feature is enabled.
+    #[cfg(feature = "jpeg")]
+    fn to_jpeg(&self, filename: &str, scale: f32, quality: u8) -> Result<(), Error> {
+        std::fs::write(filename, self.to_jpeg_bytes(scale, quality)?)?;
+        Ok(())
+    }
+
+    /// Converts the chart to JPEG bytes at the given quality from 1 to 100, scaling the size
+    /// of the image by the given scale factor.
+    ///
+    /// This method is only available when the "jpeg" feature is enabled.
+    #[cfg(feature = "jpeg")]
+    fn to_jpeg_bytes(&self, scale: f32, quality: u8) -> Result<Vec<u8>, Error> {
+        encode_jpeg(&self.to_document()?, database(), scale, quality)
+    }
+
+    /// Saves the chart as a lossless WebP file, scaling the size of the image by the given
+    /// scale factor.
+    ///
+    /// This method is only available when the "webp" feature is enabled.
+    #[cfg(feature = "webp")]
+    fn to_webp(&self, filename: &str, scale: f32) -> Result<(), Error> {
+        std::fs::write(filename, self.to_webp_bytes(scale)?)?;
+        Ok(())
+    }
+
+    /// Converts the chart to lossless WebP bytes, scaling the size of the image by the given
+    /// scale factor.
+    ///
+    /// This method is only available when the "webp" feature is enabled.
+    #[cfg(feature = "webp")]
+    fn to_webp_bytes(&self, scale: f32) -> Result<Vec<u8>, Error> {
+        encode_webp(&self.to_document()?, database(), scale)
+    }
+
+    /// Saves the chart as a vector PDF file with a page the size of its dimensions in points.
+    ///
+    /// This method is only available when the "pdf" feature is enabled.
+    #[cfg(feature = "pdf")]
+    fn to_pdf(&self, filename: &str) -> Result<(), Error> {
+        std::fs::write(filename, self.to_pdf_bytes()?)?;
+        Ok(())
+    }
+
+    /// Converts the chart to PDF bytes, embedding the fonts used by its text.
+    ///
+    /// This method is only available when the "pdf" feature is enabled.
+    #[cfg(feature = "pdf")]
+    fn to_pdf_bytes(&self) -> Result<Vec<u8>, Error> {
+        encode_pdf(&self.to_document()?, database())
+    }
+}
 
 /// Trait for types that can be used as plot values
 pub trait PlotValue:
@@ -14,6 +178,9 @@ pub trait PlotValue:
     /// Convert to f32 for SVG coordinate calculations
     fn to_f32(self) -> f32;
 
+    /// Convert to f64 without losing range, for validating data before it is drawn
+    fn to_f64(self) -> f64;
+
     /// Maximum value for this type
     fn max_value() -> Self;
 
@@ -31,6 +198,9 @@ impl PlotValue for f32 {
     fn to_f32(self) -> f32 {
         self
     }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
     fn max_value() -> Self {
         f32::MAX
     }
@@ -49,6 +219,9 @@ impl PlotValue for f64 {
     fn to_f32(self) -> f32 {
         self as f32
     }
+    fn to_f64(self) -> f64 {
+        self
+    }
     fn max_value() -> Self {
         f64::MAX
     }
@@ -67,6 +240,9 @@ impl PlotValue for i32 {
     fn to_f32(self) -> f32 {
         self as f32
     }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
     fn max_value() -> Self {
         i32::MAX
     }
@@ -85,6 +261,9 @@ impl PlotValue for i64 {
     fn to_f32(self) -> f32 {
         self as f32
     }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
     fn max_value() -> Self {
         i64::MAX
     }
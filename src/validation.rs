@@ -0,0 +1,84 @@
+//! Checks for likely mistakes in a chart's configuration, run by `Plot::validate`.
+
+use std::fmt;
+
+/// How serious a validation issue is
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    /// The chart renders, but probably not as intended
+    Warning,
+    /// The chart cannot show what was asked of it
+    Error,
+}
+
+/// A problem found by `Plot::validate`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Issue {
+    /// Zero or negative values on a log-scale axis, which are drawn at 0.001.
+    /// An error when the series has no positive values at all.
+    NonPositiveLogData {
+        axis: &'static str,
+        series: String,
+        count: usize,
+        total: usize,
+    },
+    /// A manual range whose minimum equals its maximum
+    EmptyRange { axis: &'static str },
+    /// An auxiliary array whose length differs from the series data
+    MismatchedLength {
+        series: String,
+        field: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    /// A series with every point outside the manual range of an axis
+    DataOutsideRange { axis: &'static str, series: String },
+    /// Several legend entries with the same name
+    DuplicateLegendName { name: String, count: usize },
+}
+
+impl Issue {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Issue::NonPositiveLogData { count, total, .. } if count == total => Severity::Error,
+            Issue::NonPositiveLogData { .. } => Severity::Warning,
+            Issue::EmptyRange { .. } => Severity::Error,
+            Issue::MismatchedLength { .. } => Severity::Error,
+            Issue::DataOutsideRange { .. } => Severity::Warning,
+            Issue::DuplicateLegendName { .. } => Severity::Warning,
+        }
+    }
+}
+
+/// Quotes a series name, or describes it by position when it has none
+pub(crate) fn series_label(kind: &str, index: usize, name: &str) -> String {
+    if name.is_empty() {
+        format!("{kind} {}", index + 1)
+    } else {
+        format!("\"{name}\"")
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::NonPositiveLogData { axis, series, count, total } => write!(
+                f,
+                "{series} has {count} of {total} {axis} values at or below zero on a log scale"
+            ),
+            Issue::EmptyRange { axis } => {
+                write!(f, "the manual {axis} range has the same minimum and maximum")
+            }
+            Issue::MismatchedLength { series, field, expected, found } => write!(
+                f,
+                "{series} has {found} {field} values for {expected} data points"
+            ),
+            Issue::DataOutsideRange { axis, series } => {
+                write!(f, "every point of {series} lies outside the manual {axis} range")
+            }
+            Issue::DuplicateLegendName { name, count } => {
+                write!(f, "{count} legend entries are named \"{name}\"")
+            }
+        }
+    }
+}
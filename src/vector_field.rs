@@ -0,0 +1,373 @@
+use crate::{
+    color::Color,
+    colormap::Colormap,
+    traits::PlotValue,
+    elements::{ArrowHead, ArrowScale, FieldMode},
+};
+use bon::Builder;
+
+/// A 2D vector field where each point is `(x, y, u, v)`.
+#[derive(Clone, Builder)]
+pub struct VectorField<'a, T = f32> {
+    pub data: Vec<(T, T, T, T)>,
+    #[builder(default = "")]
+    pub name: &'a str,
+    #[builder(default = FieldMode::Quiver)]
+    pub mode: FieldMode,
+    #[builder(default = Color::Black, into)]
+    pub color: Color,
+    /// Color arrows and streamlines by vector magnitude instead of `color`
+    pub colormap: Option<Colormap>,
+    #[builder(default = ArrowScale::Auto)]
+    pub scale: ArrowScale,
+    #[builder(default = 1.0)]
+    pub line_width: f32,
+    #[builder(default = ArrowHead::Filled)]
+    pub head: ArrowHead,
+    /// Arrow head length in pixels
+    #[builder(default = 6.0)]
+    pub head_length: f32,
+    /// Arrow head width in pixels
+    #[builder(default = 4.0)]
+    pub head_width: f32,
+    /// Relative spacing of streamlines; higher values draw more lines
+    #[builder(default = 1.0)]
+    pub density: f32,
+    /// List this field in the legend
+    #[builder(default = true)]
+    pub show_in_legend: bool,
+}
+
+impl<'a, T: PlotValue> VectorField<'a, T> {
+    /// Range of vector magnitudes in the field as (min, max)
+    pub(crate) fn magnitude_range(&self) -> (f32, f32) {
+        self.data
+            .iter()
+            .map(|&(_, _, u, v)| u.to_f32().hypot(v.to_f32()))
+            .fold((f32::INFINITY, 0.0f32), |(lo, hi), m| (lo.min(m), hi.max(m)))
+    }
+
+    /// Color for a vector of magnitude `magnitude`, following the colormap when set
+    pub(crate) fn color_for(&self, magnitude: f32, range: (f32, f32)) -> Color {
+        match self.colormap {
+            Some(colormap) => {
+                let (lo, hi) = range;
+                let t = if hi - lo > f32::EPSILON {
+                    (magnitude - lo) / (hi - lo)
+                } else {
+                    1.0
+                };
+                colormap.sample(t)
+            }
+            None => self.color.clone(),
+        }
+    }
+
+    /// Integrates streamlines through the field, returning each line as `(x, y, speed)`
+    /// points in data coordinates.
+    pub(crate) fn streamlines(&self) -> Vec<Vec<(f32, f32, f32)>> {
+        let points: Vec<(f32, f32, f32, f32)> = self
+            .data
+            .iter()
+            .map(|&(x, y, u, v)| (x.to_f32(), y.to_f32(), u.to_f32(), v.to_f32()))
+            .collect();
+        if points.len() < 2 {
+            return Vec::new();
+        }
+        let (x_min, x_max) = points
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), p| (lo.min(p.0), hi.max(p.0)));
+        let (y_min, y_max) = points
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), p| (lo.min(p.1), hi.max(p.1)));
+        let extent = (x_max - x_min, y_max - y_min);
+        if extent.0 <= 0.0 || extent.1 <= 0.0 {
+            return Vec::new();
+        }
+
+        let index = FieldIndex::new(&points, (x_min, y_min), extent);
+
+        // Occupancy grid in normalized coordinates keeps streamlines evenly spaced
+        let cells = ((25.0 * self.density).round() as usize).max(2);
+        let cell_of = |nx: f32, ny: f32| -> usize {
+            let cx = ((nx * cells as f32) as usize).min(cells - 1);
+            let cy = ((ny * cells as f32) as usize).min(cells - 1);
+            cy * cells + cx
+        };
+        let mut occupied = vec![false; cells * cells];
+        let step = 0.5 / cells as f32;
+        let max_steps = cells * 20;
+
+        // Direction of travel in normalized coordinates, or None where the field vanishes
+        let velocity = |nx: f32, ny: f32| -> Option<(f32, f32, f32)> {
+            let (u, v) = index.sample(nx, ny);
+            let (du, dv) = (u / extent.0, v / extent.1);
+            let norm = du.hypot(dv);
+            (norm > 1e-9).then(|| (du / norm, dv / norm, u.hypot(v)))
+        };
+
+        let mut lines = Vec::new();
+        for seed in 0..cells * cells {
+            if occupied[seed] {
+                continue;
+            }
+            let seed_x = ((seed % cells) as f32 + 0.5) / cells as f32;
+            let seed_y = ((seed / cells) as f32 + 0.5) / cells as f32;
+
+            let mut visited = vec![seed];
+            let mut halves: Vec<Vec<(f32, f32, f32)>> = Vec::new();
+            for direction in [1.0f32, -1.0] {
+                let mut half = Vec::new();
+                let (mut nx, mut ny) = (seed_x, seed_y);
+                let mut current_cell = seed;
+                for _ in 0..max_steps {
+                    // Midpoint (RK2) integration step
+                    let Some((dx1, dy1, speed)) = velocity(nx, ny) else { break };
+                    let (mx, my) = (nx + dx1 * step * 0.5 * direction, ny + dy1 * step * 0.5 * direction);
+                    let Some((dx2, dy2, _)) = velocity(mx, my) else { break };
+                    half.push((nx, ny, speed));
+                    nx += dx2 * step * direction;
+                    ny += dy2 * step * direction;
+                    if !(0.0..=1.0).contains(&nx) || !(0.0..=1.0).contains(&ny) {
+                        break;
+                    }
+                    let cell = cell_of(nx, ny);
+                    if cell != current_cell {
+                        if occupied[cell] || visited.contains(&cell) {
+                            break;
+                        }
+                        visited.push(cell);
+                        current_cell = cell;
+                    }
+                }
+                halves.push(half);
+            }
+
+            let backward = halves.pop().unwrap_or_default();
+            let forward = halves.pop().unwrap_or_default();
+            let line: Vec<(f32, f32, f32)> = backward
+                .into_iter()
+                .skip(1)
+                .rev()
+                .chain(forward)
+                .map(|(nx, ny, speed)| (x_min + nx * extent.0, y_min + ny * extent.1, speed))
+                .collect();
+            if line.len() >= 3 {
+                for cell in visited {
+                    occupied[cell] = true;
+                }
+                lines.push(line);
+            }
+        }
+        lines
+    }
+}
+
+/// Field data points bucketed into a square grid over the field's normalized extent, so
+/// that sampling only visits the buckets around a position instead of every point.
+struct FieldIndex {
+    /// Points as normalized `(x, y)` and vector `(u, v)`, ordered by bucket
+    points: Vec<(f32, f32, f32, f32)>,
+    /// Buckets along each side
+    buckets: usize,
+    /// Offset in `points` where each bucket starts, plus the end of the last
+    starts: Vec<usize>,
+}
+
+impl FieldIndex {
+    fn new(points: &[(f32, f32, f32, f32)], origin: (f32, f32), extent: (f32, f32)) -> Self {
+        // About one point per bucket
+        let buckets = ((points.len() as f32).sqrt().ceil() as usize).max(1);
+        let normalized: Vec<(f32, f32, f32, f32)> = points
+            .iter()
+            .map(|&(x, y, u, v)| ((x - origin.0) / extent.0, (y - origin.1) / extent.1, u, v))
+            .collect();
+        let bucket_of = |nx: f32, ny: f32| {
+            let (bx, by) = Self::cell(buckets, nx, ny);
+            by * buckets + bx
+        };
+
+        // Counting sort of the points by bucket
+        let mut starts = vec![0; buckets * buckets + 1];
+        for &(nx, ny, _, _) in &normalized {
+            starts[bucket_of(nx, ny) + 1] += 1;
+        }
+        for bucket in 0..buckets * buckets {
+            starts[bucket + 1] += starts[bucket];
+        }
+        let mut next = starts.clone();
+        let mut sorted = vec![(0.0, 0.0, 0.0, 0.0); normalized.len()];
+        for point in normalized {
+            let bucket = bucket_of(point.0, point.1);
+            sorted[next[bucket]] = point;
+            next[bucket] += 1;
+        }
+        Self {
+            points: sorted,
+            buckets,
+            starts,
+        }
+    }
+
+    /// Bucket column and row holding a normalized position, clamped to the grid
+    fn cell(buckets: usize, nx: f32, ny: f32) -> (usize, usize) {
+        let index = |n: f32| ((n.max(0.0) * buckets as f32) as usize).min(buckets - 1);
+        (index(nx), index(ny))
+    }
+
+    /// Samples the field at a normalized position by inverse-distance weighting of the four
+    /// nearest data points.
+    fn sample(&self, nx: f32, ny: f32) -> (f32, f32) {
+        let (bx, by) = Self::cell(self.buckets, nx, ny);
+        let mut nearest = [(f32::INFINITY, 0.0f32, 0.0f32); 4];
+        for ring in 0..self.buckets {
+            // Every point in this ring of buckets or beyond is at least this far away
+            let reach = ring.saturating_sub(1) as f32 / self.buckets as f32;
+            if nearest[3].0 <= reach * reach {
+                break;
+            }
+            let rows = by.saturating_sub(ring)..=(by + ring).min(self.buckets - 1);
+            for row in rows {
+                let columns = bx.saturating_sub(ring)..=(bx + ring).min(self.buckets - 1);
+                for column in columns {
+                    if row.abs_diff(by).max(column.abs_diff(bx)) != ring {
+                        continue;
+                    }
+                    let bucket = row * self.buckets + column;
+                    let points = &self.points[self.starts[bucket]..self.starts[bucket + 1]];
+                    for &(px, py, u, v) in points {
+                        let d2 = (px - nx).powi(2) + (py - ny).powi(2);
+                        if d2 < 1e-12 {
+                            return (u, v);
+                        }
+                        if d2 < nearest[3].0 {
+                            nearest[3] = (d2, u, v);
+                            nearest.sort_by(|a, b| a.0.total_cmp(&b.0));
+                        }
+                    }
+                }
+            }
+        }
+        let (mut weight_sum, mut u_sum, mut v_sum) = (0.0, 0.0, 0.0);
+        for &(d2, u, v) in nearest.iter().filter(|n| n.0.is_finite()) {
+            let weight = 1.0 / d2;
+            weight_sum += weight;
+            u_sum += u * weight;
+            v_sum += v * weight;
+        }
+        if weight_sum > 0.0 {
+            (u_sum / weight_sum, v_sum / weight_sum)
+        } else {
+            (0.0, 0.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A field on an `n` by `n` grid over the unit square
+    fn grid(n: usize, vector: impl Fn(f32, f32) -> (f32, f32)) -> VectorField<'static> {
+        let step = 1.0 / (n - 1) as f32;
+        let data = (0..n * n)
+            .map(|i| {
+                let (x, y) = ((i % n) as f32 * step, (i / n) as f32 * step);
+                let (u, v) = vector(x, y);
+                (x, y, u, v)
+            })
+            .collect();
+        VectorField::builder().data(data).mode(FieldMode::Streamlines).build()
+    }
+
+    #[test]
+    fn uniform_field_gives_straight_evenly_spaced_streamlines() {
+        let lines = grid(6, |_, _| (1.0, 0.0)).streamlines();
+        // One line through each row of the 25 by 25 seeding grid
+        assert_eq!(lines.len(), 25);
+        for (row, line) in lines.iter().enumerate() {
+            let y = (row as f32 + 0.5) / 25.0;
+            assert!(line.iter().all(|p| (p.1 - y).abs() < 1e-5 && (p.2 - 1.0).abs() < 1e-5));
+            assert!(line.windows(2).all(|pair| pair[1].0 > pair[0].0));
+            // Integrated both ways from the seed to the edges of the field
+            assert!(line[0].0 < 0.03 && line[line.len() - 1].0 > 0.97, "{row}");
+        }
+    }
+
+    #[test]
+    fn density_sets_the_seeding_grid() {
+        let mut field = grid(6, |_, _| (0.0, 1.0));
+        field.density = 2.0;
+        assert_eq!(field.streamlines().len(), 50);
+    }
+
+    #[test]
+    fn rotating_field_gives_circular_streamlines() {
+        let lines = grid(21, |x, y| (-(y - 0.5), x - 0.5)).streamlines();
+        assert!(!lines.is_empty());
+        for line in &lines {
+            let radius = |p: &(f32, f32, f32)| (p.0 - 0.5).hypot(p.1 - 0.5);
+            let (first, last) = (radius(&line[0]), radius(&line[line.len() - 1]));
+            // The midpoint steps keep the radius within a small fraction of a seeding cell
+            assert!((first - last).abs() < 0.01, "{first} {last}");
+        }
+    }
+
+    #[test]
+    fn vanishing_or_degenerate_fields_have_no_streamlines() {
+        assert!(grid(5, |_, _| (0.0, 0.0)).streamlines().is_empty());
+
+        let single = VectorField::builder().data(vec![(0.0, 0.0, 1.0, 0.0)]).build();
+        assert!(single.streamlines().is_empty());
+        // All points on one horizontal line leave no extent in y
+        let flat = VectorField::builder()
+            .data(vec![(0.0, 0.0, 1.0, 0.0), (1.0, 0.0, 1.0, 0.0), (2.0, 0.0, 1.0, 0.0)])
+            .build();
+        assert!(flat.streamlines().is_empty());
+    }
+
+    #[test]
+    fn streamlines_stop_where_the_field_vanishes() {
+        // Flow to the right that is zero over the right half
+        let lines = grid(11, |x, _| (if x < 0.5 { 1.0 } else { 0.0 }, 0.0)).streamlines();
+        assert!(!lines.is_empty());
+        assert!(lines.iter().flatten().all(|p| p.0 < 0.6));
+    }
+
+    #[test]
+    fn index_sampling_matches_a_scan_of_every_point() {
+        // Irregular points from a small linear congruential generator
+        let mut state = 12345u32;
+        let mut next = || {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (state >> 8) as f32 / (1 << 24) as f32
+        };
+        let points: Vec<(f32, f32, f32, f32)> =
+            (0..300).map(|_| (next() * 4.0, next() * 2.0, next() - 0.5, next() - 0.5)).collect();
+        let index = FieldIndex::new(&points, (0.0, 0.0), (4.0, 2.0));
+
+        let scan = |nx: f32, ny: f32| {
+            let mut by_distance: Vec<(f32, f32, f32)> = points
+                .iter()
+                .map(|&(x, y, u, v)| ((x / 4.0 - nx).powi(2) + (y / 2.0 - ny).powi(2), u, v))
+                .collect();
+            by_distance.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let nearest = &by_distance[..4];
+            let weight_sum: f32 = nearest.iter().map(|n| 1.0 / n.0).sum();
+            let u: f32 = nearest.iter().map(|n| n.1 / n.0).sum();
+            let v: f32 = nearest.iter().map(|n| n.2 / n.0).sum();
+            (u / weight_sum, v / weight_sum)
+        };
+        for _ in 0..200 {
+            // Including positions just outside the field
+            let (nx, ny) = (next() * 1.2 - 0.1, next() * 1.2 - 0.1);
+            let (expected, actual) = (scan(nx, ny), index.sample(nx, ny));
+            assert!((expected.0 - actual.0).abs() < 1e-4, "{expected:?} {actual:?}");
+            assert!((expected.1 - actual.1).abs() < 1e-4, "{expected:?} {actual:?}");
+        }
+
+        // A data point samples to its own vector
+        let (x, y, u, v) = points[7];
+        assert_eq!(index.sample(x / 4.0, y / 2.0), (u, v));
+    }
+}